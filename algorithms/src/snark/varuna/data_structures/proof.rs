@@ -20,7 +20,7 @@ use crate::{
 
 use ahp::prover::{FourthMessage, ThirdMessage};
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{PrimeField, Zero};
 use snarkvm_utilities::{
     error,
     io::{self, Read, Write},
@@ -263,6 +263,53 @@ impl<E: PairingEngine> Proof<E> {
         &self.batch_sizes
     }
 
+    /// Returns the length of the compressed proof at the start of the given bytes,
+    /// by reading its length prefixes and skipping its group and field elements, without decoding them.
+    pub fn compressed_len(bytes: &[u8]) -> Result<usize, SerializationError> {
+        // Skips `count` elements of `size` bytes each.
+        fn skip(reader: &mut &[u8], count: usize, size: usize) -> Result<(), SerializationError> {
+            let len = count.checked_mul(size).ok_or(SerializationError::InvalidData)?;
+            *reader = reader.get(len..).ok_or(SerializationError::InvalidData)?;
+            Ok(())
+        }
+
+        let mut reader = bytes;
+        // Compute the sizes of a group element and a field element.
+        let group_size = E::G1Affine::zero().compressed_size();
+        let field_size = E::Fr::zero().compressed_size();
+
+        // Read the batch sizes.
+        let batch_sizes: Vec<u64> = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
+        let num_circuits = batch_sizes.len();
+        let num_instances = batch_sizes
+            .iter()
+            .try_fold(0usize, |acc, &size| acc.checked_add(usize::try_from(size).ok()?))
+            .ok_or(SerializationError::InvalidData)?;
+
+        // Skip the commitments.
+        skip(&mut reader, num_instances, group_size)?;
+        if bool::deserialize_compressed(&mut reader)? {
+            skip(&mut reader, 1, group_size)?;
+        }
+        skip(&mut reader, 3, group_size)?;
+        skip(&mut reader, 3 * num_circuits, group_size)?;
+        skip(&mut reader, 1, group_size)?;
+        // Skip the evaluations.
+        skip(&mut reader, 1 + 3 * num_circuits, field_size)?;
+        // Skip the third and fourth messages.
+        skip(&mut reader, 3 * num_instances, field_size)?;
+        skip(&mut reader, 3 * num_circuits, field_size)?;
+        // Skip the evaluation proofs.
+        let num_proofs = u64::deserialize_compressed(&mut reader)?;
+        for _ in 0..num_proofs {
+            skip(&mut reader, 1, group_size)?;
+            if bool::deserialize_compressed(&mut reader)? {
+                skip(&mut reader, 1, field_size)?;
+            }
+        }
+        Ok(bytes.len() - reader.len())
+    }
+
     /// Check that the number of messages is consistent with our batch size
     pub fn check_batch_sizes(&self) -> Result<(), SNARKError> {
         let total_instances = self
@@ -514,4 +561,33 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_compressed_len() {
+        let rng = &mut TestRng::default();
+
+        for i in 1..5 {
+            for j in 1..5 {
+                let test_with_none = i * j % 2 == 0;
+                let batch_sizes = vec![j; i];
+                let commitments = rand_commitments(j, i, test_with_none);
+                let evaluations: Evaluations<Fr> = rand_evaluations(rng, i);
+                let third_msg = ThirdMessage::<Fr> { sums: vec![vec![rand_sums(rng); j]; i] };
+                let fourth_msg = FourthMessage::<Fr> { sums: vec![rand_sums(rng); i] };
+                let pc_proof =
+                    sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, test_with_none); j]) };
+                let proof = Proof { batch_sizes, commitments, evaluations, third_msg, fourth_msg, pc_proof };
+
+                // Ensure the length matches the compressed size, with or without trailing bytes.
+                let mut serialized = proof.to_bytes_le().unwrap();
+                let size = serialized.len();
+                assert_eq!(size, Proof::serialized_size(&proof, Compress::Yes));
+                assert_eq!(size, Proof::<Bls12_377>::compressed_len(&serialized).unwrap());
+                serialized.extend_from_slice(&[1, 2, 3]);
+                assert_eq!(size, Proof::<Bls12_377>::compressed_len(&serialized).unwrap());
+                // Ensure a truncated proof is rejected.
+                assert!(Proof::<Bls12_377>::compressed_len(&serialized[..size - 1]).is_err());
+            }
+        }
+    }
 }
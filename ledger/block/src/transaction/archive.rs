@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::io::{BufRead, BufReader};
+
+impl<N: Network> Transaction<N> {
+    /// Reads consecutive transactions from the reader until it is exhausted, and writes each one without
+    /// its proofs to the writer, returning the number of transactions compacted.
+    ///
+    /// Each entry of the archive is a little-endian `u32` length, followed by the bytes of
+    /// `Transaction::bytes_excluding_proofs` (which begin with `Transaction::PROOFLESS_HEADER`),
+    /// so each entry may be decoded with `Transaction::from_bytes_excluding_proofs`,
+    /// or the archive with `Transaction::read_compacted_archive`.
+    ///
+    /// Note: The proofs are *not* verified, so the transactions must have been verified beforehand.
+    pub fn compact_archive<R: Read, W: Write>(reader: R, mut writer: W) -> Result<usize> {
+        let mut reader = BufReader::new(reader);
+        let mut num_transactions = 0usize;
+        // Read the transactions, until the reader is exhausted.
+        while !reader.fill_buf()?.is_empty() {
+            // Read the transaction, limiting the bytes consumed to the maximum transaction size.
+            let transaction = Self::read_le((&mut reader).take(N::MAX_TRANSACTION_SIZE as u64))?;
+            // Write the transaction, without its proofs.
+            let bytes = transaction.bytes_excluding_proofs()?;
+            u32::try_from(bytes.len())?.write_le(&mut writer)?;
+            writer.write_all(&bytes)?;
+            num_transactions += 1;
+        }
+        writer.flush()?;
+        Ok(num_transactions)
+    }
+
+    /// Reads the transactions from an archive written by `Transaction::compact_archive`.
+    /// The proofs in the returned transactions are `None`.
+    pub fn read_compacted_archive<R: Read>(reader: R) -> Result<Vec<Self>> {
+        let mut reader = BufReader::new(reader);
+        let mut transactions = Vec::new();
+        // Read the entries, until the reader is exhausted.
+        while !reader.fill_buf()?.is_empty() {
+            // Read the length of the entry.
+            let len = u32::read_le(&mut reader)? as usize;
+            // Ensure the entry does not exceed the maximum transaction size (and the header).
+            ensure!(
+                len <= N::MAX_TRANSACTION_SIZE + 1,
+                "Archived transaction exceeds the maximum size of {} bytes",
+                N::MAX_TRANSACTION_SIZE
+            );
+            // Read the entry.
+            let mut bytes = vec![0u8; len];
+            reader.read_exact(&mut bytes)?;
+            transactions.push(Self::from_bytes_excluding_proofs(&bytes)?);
+        }
+        Ok(transactions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_compact_archive() {
+        let rng = &mut TestRng::default();
+
+        let transactions = [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ];
+        // Write the transactions, back to back.
+        let mut input = Vec::new();
+        for transaction in &transactions {
+            transaction.write_le(&mut input).unwrap();
+        }
+
+        // Compact the transactions.
+        let mut archive = Vec::new();
+        assert_eq!(Transaction::<CurrentNetwork>::compact_archive(&input[..], &mut archive).unwrap(), 3);
+        assert!(archive.len() < input.len());
+
+        // Ensure each entry is decoded by the proofless decoder.
+        let mut reader = &archive[..];
+        for expected in &transactions {
+            let len = u32::read_le(&mut reader).unwrap() as usize;
+            let (entry, remaining) = reader.split_at(len);
+            assert_eq!(entry, &expected.bytes_excluding_proofs().unwrap()[..]);
+            assert_eq!(Transaction::<CurrentNetwork>::from_bytes_excluding_proofs(entry).unwrap().id(), expected.id());
+            reader = remaining;
+        }
+        assert!(reader.is_empty());
+
+        // Ensure the archive is read back, without proofs.
+        let candidates = Transaction::<CurrentNetwork>::read_compacted_archive(&archive[..]).unwrap();
+        assert_eq!(candidates.len(), transactions.len());
+        for (expected, candidate) in transactions.iter().zip(&candidates) {
+            assert_eq!(expected.id(), candidate.id());
+            assert!(expected.transitions().eq(candidate.transitions()));
+        }
+
+        // Ensure an empty input produces an empty archive.
+        let mut archive = Vec::new();
+        assert_eq!(Transaction::<CurrentNetwork>::compact_archive(&[][..], &mut archive).unwrap(), 0);
+        assert!(archive.is_empty());
+        // Ensure a truncated input, and a truncated archive, are rejected.
+        assert!(Transaction::<CurrentNetwork>::compact_archive(&input[..input.len() - 1], Vec::new()).is_err());
+        let mut archive = Vec::new();
+        Transaction::<CurrentNetwork>::compact_archive(&input[..], &mut archive).unwrap();
+        assert!(Transaction::<CurrentNetwork>::read_compacted_archive(&archive[..archive.len() - 1]).is_err());
+    }
+}
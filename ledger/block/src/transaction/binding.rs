@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::Input;
+use console::{program::compute_function_id, types::U16};
+
+/// The error returned when a transaction is not bound to the network.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NetworkBindingError<N: Network> {
+    /// No transition in the transaction has a constant, public, or future input or output.
+    Absent(N::TransactionID),
+    /// The input or output at the given index of the transition does not hash under the network ID.
+    Mismatch(N::TransitionID, usize),
+}
+
+impl<N: Network> Display for NetworkBindingError<N> {
+    /// Formats the network binding error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Absent(id) => write!(f, "Transaction '{id}' has no inputs or outputs that bind to network {}", N::ID),
+            Self::Mismatch(id, index) => {
+                write!(f, "Transition '{id}' has an input or output (at index {index}) not bound to network {}", N::ID)
+            }
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for NetworkBindingError<N> {}
+
+impl<N: Network> Transaction<N> {
+    /// Ensures the transaction is bound to the network ID (`N::ID`).
+    ///
+    /// The function ID of a transition commits to the network ID, and the hash of each constant, public,
+    /// and future input or output commits to the function ID. This checks each of these hashes under `N::ID`,
+    /// returning `NetworkBindingError::Mismatch` for the first that does not match, which indicates the transition
+    /// was produced for a different network (or its value was modified). If the transaction has no such inputs or
+    /// outputs, `NetworkBindingError::Absent` is returned, as the binding cannot be checked without the proofs.
+    ///
+    /// Note: The proofs also commit to the network ID, as the function ID is computed in the circuit,
+    /// however this is only checked when the proofs are verified, with `Process::verify_execution`.
+    pub fn verify_network_binding(&self) -> Result<()> {
+        // Retrieve the network ID.
+        let network_id = U16::new(N::ID);
+        // Track if any input or output binds to the network.
+        let mut is_bound = false;
+        for transition in self.transitions() {
+            // Compute the function ID.
+            let function_id = compute_function_id(&network_id, transition.program_id(), transition.function_name())?;
+            // Ensure each input that commits to the function ID is valid.
+            for (index, input) in transition.inputs().iter().enumerate() {
+                if let Input::Constant(_, Some(_)) | Input::Public(_, Some(_)) = input {
+                    match input.verify(function_id, transition.tcm(), index) {
+                        true => is_bound = true,
+                        false => return Err(NetworkBindingError::<N>::Mismatch(*transition.id(), index).into()),
+                    }
+                }
+            }
+            // Ensure each output that commits to the function ID is valid.
+            let num_inputs = transition.inputs().len();
+            for (index, output) in transition.outputs().iter().enumerate() {
+                if let Output::Constant(_, Some(_)) | Output::Public(_, Some(_)) | Output::Future(_, Some(_)) = output {
+                    match output.verify(function_id, transition.tcm(), num_inputs + index) {
+                        true => is_bound = true,
+                        false => {
+                            let index = num_inputs + index;
+                            return Err(NetworkBindingError::<N>::Mismatch(*transition.id(), index).into());
+                        }
+                    }
+                }
+            }
+        }
+        match is_bound {
+            true => Ok(()),
+            false => Err(NetworkBindingError::<N>::Absent(self.id()).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::program::{Literal, Plaintext};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Returns the given transition, with the given inputs and outputs.
+    fn with_io(
+        transition: &Transition<CurrentNetwork>,
+        inputs: Vec<Input<CurrentNetwork>>,
+        outputs: Vec<Output<CurrentNetwork>>,
+    ) -> Transition<CurrentNetwork> {
+        Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            inputs,
+            outputs,
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.scm(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify_network_binding() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            transaction.verify_network_binding().unwrap();
+        }
+
+        // Retrieve a transition with public inputs.
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        let transition = transaction.fee_transition().unwrap().transition().clone();
+        let index = transition.inputs().iter().position(|input| matches!(input, Input::Public(..))).unwrap();
+        // Returns a feeless execution transaction with the given transition.
+        let global_state_root = transaction.fee_transition().unwrap().global_state_root();
+        let sample_transaction = |transition| {
+            crate::transaction::test_helpers::sample_execution_transaction_with_transition(
+                transition,
+                global_state_root,
+            )
+        };
+
+        // Ensure a public input with a modified value is rejected.
+        let mut inputs = transition.inputs().to_vec();
+        let value = Plaintext::from(Literal::U64(U64::new(u64::MAX)));
+        inputs[index] = Input::Public(*inputs[index].id(), Some(value));
+        let invalid = with_io(&transition, inputs, transition.outputs().to_vec());
+        let error = sample_transaction(invalid.clone()).verify_network_binding().unwrap_err();
+        let expected = NetworkBindingError::<CurrentNetwork>::Mismatch(*invalid.id(), index);
+        assert_eq!(error.downcast_ref::<NetworkBindingError<CurrentNetwork>>(), Some(&expected));
+
+        // Ensure a transaction without constant, public, or future inputs or outputs is rejected.
+        let unbound = sample_transaction(with_io(&transition, vec![], vec![]));
+        let error = unbound.verify_network_binding().unwrap_err();
+        let expected = NetworkBindingError::<CurrentNetwork>::Absent(unbound.id());
+        assert_eq!(error.downcast_ref::<NetworkBindingError<CurrentNetwork>>(), Some(&expected));
+    }
+}
@@ -14,16 +14,32 @@
 
 use super::*;
 
+use std::sync::Arc;
+
 impl<N: Network> FromBytes for Transaction<N> {
     /// Reads the transaction from the buffer.
     #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_internal(reader, None).map_err(|err| error(format!("{err}")))
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Reads the transaction from the buffer, ensuring the verifying keys of a deployment do not exceed
+    /// `max_verifying_key_bytes` bytes. If they do, a `VerifyingKeysTooLarge` error is returned.
+    ///
+    /// Note: The limit is only enforced by this method, and `Transaction::read_le` accepts deployments of any size.
+    pub fn read_le_with_max_verifying_key_bytes<R: Read>(reader: R, max_verifying_key_bytes: usize) -> Result<Self> {
+        Self::read_le_internal(reader, Some(max_verifying_key_bytes))
+    }
+
+    /// Reads the transaction from the buffer, ensuring the verifying keys of a deployment do not exceed
+    /// the given number of bytes, if any.
+    fn read_le_internal<R: Read>(mut reader: R, max_verifying_key_bytes: Option<usize>) -> Result<Self> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid transaction version"));
-        }
+        ensure!(version == 1, "Invalid transaction version");
 
         // Read the variant.
         let variant = u8::read_le(&mut reader)?;
@@ -35,12 +51,15 @@ impl<N: Network> FromBytes for Transaction<N> {
                 // Read the owner.
                 let owner = ProgramOwner::read_le(&mut reader)?;
                 // Read the deployment.
-                let deployment = Deployment::read_le(&mut reader)?;
+                let deployment = match max_verifying_key_bytes {
+                    Some(max_bytes) => Deployment::read_le_with_max_verifying_key_bytes(&mut reader, max_bytes)?,
+                    None => Deployment::read_le(&mut reader)?,
+                };
                 // Read the fee.
                 let fee = Fee::read_le(&mut reader)?;
 
                 // Initialize the transaction.
-                let transaction = Self::from_deployment(owner, deployment, fee).map_err(|e| error(e.to_string()))?;
+                let transaction = Self::from_deployment(owner, deployment, fee)?;
                 // Return the ID and the transaction.
                 (id, transaction)
             }
@@ -56,11 +75,11 @@ impl<N: Network> FromBytes for Transaction<N> {
                 let fee = match fee_variant {
                     0u8 => None,
                     1u8 => Some(Fee::read_le(&mut reader)?),
-                    _ => return Err(error("Invalid fee variant")),
+                    _ => bail!("Invalid fee variant"),
                 };
 
                 // Initialize the transaction.
-                let transaction = Self::from_execution(execution, fee).map_err(|e| error(e.to_string()))?;
+                let transaction = Self::from_execution(execution, fee)?;
                 // Return the ID and the transaction.
                 (id, transaction)
             }
@@ -71,19 +90,179 @@ impl<N: Network> FromBytes for Transaction<N> {
                 let fee = Fee::read_le(&mut reader)?;
 
                 // Initialize the transaction.
-                let transaction = Self::from_fee(fee).map_err(|e| error(e.to_string()))?;
+                let transaction = Self::from_fee(fee)?;
                 // Return the ID and the transaction.
                 (id, transaction)
             }
-            3.. => return Err(error("Invalid transaction variant")),
+            3.. => bail!("Invalid transaction variant"),
         };
 
         // Ensure the transaction ID matches.
-        match transaction.id() == id {
-            // Return the transaction.
+        ensure!(transaction.id() == id, "Transaction ID mismatch");
+        // Return the transaction.
+        Ok(transaction)
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Reads the transaction from the buffer, directly into an `Arc`.
+    #[inline]
+    pub fn read_le_arc<R: Read>(reader: R) -> IoResult<Arc<Self>> {
+        Self::read_le(reader).map(Arc::new)
+    }
+
+    /// Reads the transaction from the buffer, invoking `progress` with the approximate fraction of the
+    /// expected length `len` (in bytes) that has been consumed.
+    ///
+    /// The fraction is reported each time at least 1% of `len` has been consumed since the last report,
+    /// and is clamped to `1.0`. Upon success, `progress` is invoked a final time with `1.0`.
+    /// Note: `progress` only observes the fraction, and has no access to the buffer being decoded.
+    pub fn read_le_with_progress<R: Read, F: FnMut(f32)>(reader: R, len: usize, mut progress: F) -> IoResult<Self> {
+        // Initialize the number of bytes consumed, and the number consumed at the last report.
+        let (mut consumed, mut reported) = (0usize, 0usize);
+        // Compute the minimum number of bytes consumed between reports.
+        let interval = (len / 100).max(1);
+        // Read the transaction, reporting the progress at each interval.
+        let transaction = Self::read_le(CountingReader::new(reader, |bytes: &[u8]| {
+            consumed = consumed.saturating_add(bytes.len());
+            if consumed.saturating_sub(reported) >= interval {
+                reported = consumed;
+                progress((consumed as f32 / len.max(1) as f32).min(1.0));
+            }
+        }))?;
+        // Report the completion.
+        progress(1.0);
+        Ok(transaction)
+    }
+
+    /// Reads the transaction from the buffer, returning it along with the exact bytes consumed to read it.
+    /// Note: As the bytes are the input itself, they are not affected by a re-serialization of the transaction.
+    pub fn read_le_with_bytes<R: Read>(reader: R) -> IoResult<(Self, Vec<u8>)> {
+        // Read the transaction, recording the bytes consumed.
+        let mut bytes = Vec::new();
+        let transaction = Self::read_le(CountingReader::new(reader, |chunk: &[u8]| bytes.extend_from_slice(chunk)))?;
+        Ok((transaction, bytes))
+    }
+
+    /// Reads the transaction from the buffer, followed by the zero padding up to a multiple of `alignment` bytes.
+    /// Returns an error if the alignment is zero, or if the padding is not zero.
+    pub fn read_le_aligned<R: Read>(reader: R, alignment: usize) -> IoResult<Self> {
+        // Ensure the alignment is nonzero.
+        if alignment == 0 {
+            return Err(error("The transaction alignment must be nonzero"));
+        }
+        // Wrap the reader, to count the bytes consumed.
+        let mut reader = CountingReader::new(reader, |_: &[u8]| ());
+        // Read the transaction.
+        let transaction = Self::read_le(&mut reader)?;
+        // Read the padding.
+        let mut padding = vec![0u8; (alignment - reader.consumed() % alignment) % alignment];
+        reader.read_exact(&mut padding)?;
+        // Ensure the padding is zero.
+        match padding.iter().all(|byte| *byte == 0) {
             true => Ok(transaction),
-            false => Err(error("Transaction ID mismatch")),
+            false => Err(error("Found nonzero padding after the transaction")),
+        }
+    }
+
+    /// Writes the transaction to the buffer, followed by zero padding up to a multiple of `alignment` bytes.
+    /// Returns an error if the alignment is zero.
+    pub fn write_le_aligned<W: Write>(&self, mut writer: W, alignment: usize) -> IoResult<()> {
+        // Ensure the alignment is nonzero.
+        if alignment == 0 {
+            return Err(error("The transaction alignment must be nonzero"));
+        }
+        // Write the transaction.
+        let bytes = self.to_bytes_le().map_err(|e| error(e.to_string()))?;
+        writer.write_all(&bytes)?;
+        // Write the padding.
+        writer.write_all(&vec![0u8; (alignment - bytes.len() % alignment) % alignment])
+    }
+
+    /// Reads the transaction from a hex string, tolerating an optional `0x` or `0X` prefix and surrounding whitespace.
+    /// Note: The decoded bytes may not exceed `N::MAX_TRANSACTION_SIZE`, and must be consumed in full.
+    pub fn from_hex(string: &str) -> Result<Self> {
+        // Trim the surrounding whitespace.
+        let string = string.trim();
+        // Strip the prefix, if it exists.
+        let string = string.strip_prefix("0x").or_else(|| string.strip_prefix("0X")).unwrap_or(string);
+        // Ensure the decoded bytes do not exceed the maximum transaction size.
+        ensure!(
+            string.len() <= 2 * N::MAX_TRANSACTION_SIZE,
+            "Transaction exceeds the maximum size of {} bytes",
+            N::MAX_TRANSACTION_SIZE
+        );
+        // Decode the bytes.
+        let bytes = hex::decode(string)?;
+        // Read the transaction.
+        let mut reader = &bytes[..];
+        let transaction = Self::read_le(&mut reader)?;
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the transaction", reader.len());
+        Ok(transaction)
+    }
+
+    /// Collects transactions from the given iterator until the next transaction would exceed the size budget
+    /// (in bytes), returning the collected transactions, and the first transaction that did not fit (if any).
+    ///
+    /// The iterator is not consumed beyond the first transaction that did not fit,
+    /// so the caller may pass `&mut iter` to continue from there in the next batch.
+    /// Note: A transaction that alone exceeds the budget is returned as the one that did not fit, with an empty batch.
+    pub fn collect_within_size(
+        iter: impl IntoIterator<Item = Self>,
+        budget: usize,
+    ) -> Result<(Vec<Self>, Option<Self>)> {
+        // Initialize the batch and its cumulative size.
+        let mut batch = Vec::new();
+        let mut size = 0usize;
+        for transaction in iter {
+            // Compute the size of the transaction.
+            let transaction_size = transaction.to_bytes_le()?.len();
+            // If the transaction does not fit, return the batch and the transaction.
+            match size.checked_add(transaction_size) {
+                Some(next_size) if next_size <= budget => size = next_size,
+                _ => return Ok((batch, Some(transaction))),
+            }
+            batch.push(transaction);
         }
+        Ok((batch, None))
+    }
+
+    /// Returns the transaction as a hex string, without a prefix.
+    pub fn to_hex(&self) -> Result<String> {
+        Ok(hex::encode(self.to_bytes_le()?))
+    }
+}
+
+/// A reader that counts the bytes consumed from the inner reader, and passes each chunk it reads to `on_read`.
+pub(super) struct CountingReader<R: Read, F: FnMut(&[u8])> {
+    /// The inner reader.
+    reader: R,
+    /// The number of bytes consumed.
+    consumed: usize,
+    /// The callback, invoked with each chunk read.
+    on_read: F,
+}
+
+impl<R: Read, F: FnMut(&[u8])> CountingReader<R, F> {
+    /// Initializes a new counting reader, which invokes `on_read` with each chunk read.
+    pub(super) const fn new(reader: R, on_read: F) -> Self {
+        Self { reader, consumed: 0, on_read }
+    }
+
+    /// Returns the number of bytes consumed.
+    pub(super) const fn consumed(&self) -> usize {
+        self.consumed
+    }
+}
+
+impl<R: Read, F: FnMut(&[u8])> Read for CountingReader<R, F> {
+    /// Reads from the inner reader, counting the bytes read and passing them to the callback.
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let num_bytes = self.reader.read(buf)?;
+        self.consumed = self.consumed.saturating_add(num_bytes);
+        (self.on_read)(&buf[..num_bytes]);
+        Ok(num_bytes)
     }
 }
 
@@ -140,6 +319,8 @@ impl<N: Network> ToBytes for Transaction<N> {
 mod tests {
     use super::*;
 
+    type CurrentNetwork = console::network::MainnetV0;
+
     #[test]
     fn test_bytes() -> Result<()> {
         let rng = &mut TestRng::default();
@@ -155,7 +336,255 @@ mod tests {
             // Check the byte representation.
             let expected_bytes = expected.to_bytes_le()?;
             assert_eq!(expected, Transaction::read_le(&expected_bytes[..])?);
+            assert_eq!(expected, *Transaction::read_le_arc(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_le_with_max_verifying_key_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new deployment transaction.
+        let expected = crate::transaction::test_helpers::sample_deployment_transaction(true, rng);
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Compute the number of bytes in the verifying keys.
+        let mut num_verifying_key_bytes = 0;
+        for (_, (verifying_key, _)) in expected.deployment().unwrap().verifying_keys() {
+            num_verifying_key_bytes += verifying_key.to_bytes_le()?.len();
+        }
+
+        // Ensure the transaction is read when the verifying keys are within the limit.
+        let max_bytes = num_verifying_key_bytes;
+        assert_eq!(expected, Transaction::read_le_with_max_verifying_key_bytes(&expected_bytes[..], max_bytes)?);
+        // Ensure the transaction is rejected with a typed error when the verifying keys exceed the limit,
+        // even though it is within the maximum transaction size.
+        assert!(expected_bytes.len() <= CurrentNetwork::MAX_TRANSACTION_SIZE);
+        let max_bytes = num_verifying_key_bytes - 1;
+        let error = Transaction::<CurrentNetwork>::read_le_with_max_verifying_key_bytes(&expected_bytes[..], max_bytes)
+            .unwrap_err();
+        assert_eq!(error.downcast_ref::<VerifyingKeysTooLarge>().unwrap().max_bytes, max_bytes);
+        // Ensure the default reader is not limited.
+        assert_eq!(expected, Transaction::read_le(&expected_bytes[..])?);
+
+        // Ensure the limit does not apply to transactions without a deployment.
+        for expected in [
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Transaction::read_le_with_max_verifying_key_bytes(&expected_bytes[..], 0)?);
         }
         Ok(())
     }
+
+    #[test]
+    fn test_read_le_with_progress() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new transaction.
+        let expected = crate::transaction::test_helpers::sample_deployment_transaction(true, rng);
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Ensure the transaction is read, and the progress is reported in increasing order.
+        let mut fractions = Vec::new();
+        let candidate =
+            Transaction::read_le_with_progress(&expected_bytes[..], expected_bytes.len(), |f| fractions.push(f))?;
+        assert_eq!(expected, candidate);
+        assert!(fractions.len() > 2);
+        assert!(fractions.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(fractions.iter().all(|f| (0.0..=1.0).contains(f)));
+        assert_eq!(fractions.last(), Some(&1.0));
+
+        // Ensure an underestimated length is clamped.
+        let mut fractions = Vec::new();
+        Transaction::<CurrentNetwork>::read_le_with_progress(&expected_bytes[..], 1, |f| fractions.push(f))?;
+        assert!(fractions.iter().all(|f| *f == 1.0));
+
+        // Ensure a truncated buffer fails to read.
+        let truncated = &expected_bytes[..expected_bytes.len() - 1];
+        assert!(Transaction::<CurrentNetwork>::read_le_with_progress(truncated, truncated.len(), |_| ()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_le_with_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            // Append trailing bytes, which must not be consumed.
+            let expected_bytes = expected.to_bytes_le()?;
+            let mut buffer = expected_bytes.clone();
+            buffer.extend_from_slice(&[1, 2, 3]);
+
+            // Ensure the transaction is read, along with exactly its bytes.
+            let mut reader = &buffer[..];
+            let (candidate, bytes) = Transaction::<CurrentNetwork>::read_le_with_bytes(&mut reader)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(expected_bytes, bytes);
+            assert_eq!(reader, &[1, 2, 3]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_aligned() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let transactions = [
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ];
+        for alignment in [1, 8, 64] {
+            // Write the transactions, back to back.
+            let mut buffer = Vec::new();
+            for transaction in &transactions {
+                transaction.write_le_aligned(&mut buffer, alignment)?;
+                assert_eq!(buffer.len() % alignment, 0);
+            }
+            // Ensure the transactions are read back.
+            let mut reader = &buffer[..];
+            for expected in &transactions {
+                assert_eq!(expected, &Transaction::read_le_aligned(&mut reader, alignment)?);
+            }
+            assert!(reader.is_empty());
+        }
+
+        // Ensure nonzero padding is rejected.
+        let transaction = &transactions[1];
+        let size = transaction.to_bytes_le()?.len();
+        let alignment = size + 1;
+        let mut buffer = Vec::new();
+        transaction.write_le_aligned(&mut buffer, alignment)?;
+        assert_eq!(buffer.len(), alignment);
+        *buffer.last_mut().unwrap() = 1;
+        assert!(Transaction::<CurrentNetwork>::read_le_aligned(&buffer[..], alignment).is_err());
+        // Ensure missing padding, and a zero alignment, are rejected.
+        assert!(Transaction::<CurrentNetwork>::read_le_aligned(&buffer[..size], alignment).is_err());
+        assert!(Transaction::<CurrentNetwork>::read_le_aligned(&buffer[..], 0).is_err());
+        assert!(transaction.write_le_aligned(&mut Vec::new(), 0).is_err());
+        Ok(())
+    }
+
+    /// A position at which to corrupt the bytes of a transaction.
+    #[derive(Clone, Debug)]
+    enum CorruptionPoint {
+        /// Flip the version byte.
+        Version,
+        /// Flip the variant byte.
+        Variant,
+        /// Flip the first byte of the given field.
+        FieldFirstByte(&'static str),
+        /// Truncate the bytes at the start of the given field.
+        TruncateAt(&'static str),
+        /// Truncate the last byte.
+        TruncateLast,
+    }
+
+    /// Returns the corruption points of the given transaction bytes.
+    fn corruption_points(bytes: &[u8]) -> Vec<CorruptionPoint> {
+        let index = TransactionIndex::<CurrentNetwork>::new(bytes).unwrap();
+        let mut points = vec![CorruptionPoint::Version, CorruptionPoint::Variant, CorruptionPoint::TruncateLast];
+        for name in index.field_names() {
+            points.push(CorruptionPoint::FieldFirstByte(name));
+            points.push(CorruptionPoint::TruncateAt(name));
+        }
+        points
+    }
+
+    /// Returns a copy of the given transaction bytes, corrupted at the given position.
+    fn corrupt_at(bytes: &[u8], position: &CorruptionPoint) -> Vec<u8> {
+        let index = TransactionIndex::<CurrentNetwork>::new(bytes).unwrap();
+        let mut bytes = bytes.to_vec();
+        match position {
+            CorruptionPoint::Version => bytes[0] ^= 0xFF,
+            CorruptionPoint::Variant => bytes[1] ^= 0xFF,
+            CorruptionPoint::FieldFirstByte(name) => bytes[index.range(name).unwrap().start] ^= 0xFF,
+            CorruptionPoint::TruncateAt(name) => bytes.truncate(index.range(name).unwrap().start),
+            CorruptionPoint::TruncateLast => {
+                bytes.pop();
+            }
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_corrupted_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let expected_bytes = expected.to_bytes_le()?;
+            // Ensure each corruption is rejected.
+            for position in corruption_points(&expected_bytes) {
+                let corrupted = corrupt_at(&expected_bytes, &position);
+                assert_ne!(expected_bytes, corrupted);
+                assert!(
+                    Transaction::<CurrentNetwork>::read_le(&corrupted[..]).is_err(),
+                    "Corruption at {position:?} was not rejected"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_within_size() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct new transactions.
+        let transactions = vec![
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_private_fee_transaction(rng),
+        ];
+        let sizes =
+            transactions.iter().map(|tx| tx.to_bytes_le().map(|bytes| bytes.len())).collect::<Result<Vec<_>>>()?;
+
+        // Ensure all transactions are collected within a sufficient budget.
+        let (batch, remaining) = Transaction::collect_within_size(transactions.clone(), sizes.iter().sum())?;
+        assert_eq!(batch, transactions);
+        assert!(remaining.is_none());
+
+        // Ensure the collection stops at the first transaction that does not fit, and the rest can be resumed.
+        let mut iter = transactions.clone().into_iter();
+        let (batch, remaining) = Transaction::collect_within_size(&mut iter, sizes[0] + sizes[1] - 1)?;
+        assert_eq!(batch, transactions[..1]);
+        assert_eq!(remaining.as_ref(), Some(&transactions[1]));
+        assert_eq!(iter.collect::<Vec<_>>(), transactions[2..]);
+
+        // Ensure a transaction that exceeds the budget alone is returned with an empty batch.
+        let (batch, remaining) = Transaction::collect_within_size(transactions.clone(), sizes[0] - 1)?;
+        assert!(batch.is_empty());
+        assert_eq!(remaining.as_ref(), Some(&transactions[0]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hex() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new transaction.
+        let expected = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+
+        // Check the hex representation, with and without a prefix.
+        let expected_hex = expected.to_hex()?;
+        assert_eq!(expected, Transaction::from_hex(&expected_hex)?);
+        assert_eq!(expected, Transaction::from_hex(&format!("0x{expected_hex}"))?);
+        assert_eq!(expected, Transaction::from_hex(&format!(" 0X{expected_hex}\n"))?);
+
+        // Ensure malformed hex strings are rejected.
+        assert!(Transaction::<CurrentNetwork>::from_hex(&format!("0x0x{expected_hex}")).is_err());
+        assert!(Transaction::<CurrentNetwork>::from_hex(&format!("{expected_hex}00")).is_err());
+        assert!(Transaction::<CurrentNetwork>::from_hex(&expected_hex[..expected_hex.len() - 2]).is_err());
+        Ok(())
+    }
 }
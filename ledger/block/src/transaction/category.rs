@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The high-level category of a transaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransactionCategory {
+    /// The transaction deploys a program.
+    Deploy,
+    /// The transaction executes one of the `credits.aleo` transfer functions.
+    CreditsTransfer,
+    /// The transaction executes any other program function.
+    ProgramCall,
+    /// The transaction only pays a fee, as it was rejected.
+    Fee,
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the category of the transaction.
+    ///
+    /// An execute transaction is a `CreditsTransfer` if its root transition (the function called by the user)
+    /// is one of the `credits.aleo` transfer functions, and a `ProgramCall` otherwise.
+    pub fn category(&self) -> TransactionCategory {
+        match self {
+            Self::Deploy(..) => TransactionCategory::Deploy,
+            // Note: `Execution::peek` underflows on an empty execution, so the root transition is read directly.
+            Self::Execute(_, execution, _) => match execution.transitions().next_back() {
+                Some(transition) if transition.is_credits_transfer() => TransactionCategory::CreditsTransfer,
+                _ => TransactionCategory::ProgramCall,
+            },
+            Self::Fee(..) => TransactionCategory::Fee,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::MainnetV0, program::Address};
+    use synthesizer_process::Process;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Returns an unproven, feeless execution transaction, which calls the given function.
+    fn sample_execution_transaction(
+        process: &Process<CurrentNetwork>,
+        program_id: &str,
+        function_name: &str,
+        inputs: &[String],
+        rng: &mut TestRng,
+    ) -> Transaction<CurrentNetwork> {
+        // Authorize the function call.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let authorization =
+            process.authorize::<CurrentAleo, _>(&private_key, program_id, function_name, inputs.iter(), rng).unwrap();
+        // Note: This is a testing-only hack to adhere to Rust's dependency cycle rules.
+        let transitions =
+            authorization.transitions().into_values().map(|t| Transition::from_str(&t.to_string()).unwrap());
+        // Construct the execution transaction.
+        let execution = Execution::from(transitions, Default::default(), None).unwrap();
+        Transaction::from_execution(execution, None).unwrap()
+    }
+
+    #[test]
+    fn test_category() {
+        let rng = &mut TestRng::default();
+
+        let transaction = crate::transaction::test_helpers::sample_deployment_transaction(true, rng);
+        assert_eq!(transaction.category(), TransactionCategory::Deploy);
+
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        assert_eq!(transaction.category(), TransactionCategory::Fee);
+    }
+
+    #[test]
+    fn test_category_execute() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a process with a program that does not call `credits.aleo`.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let program = synthesizer_program::Program::<CurrentNetwork>::from_str(
+            r"
+program category_test.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+        )
+        .unwrap();
+        process.add_program(&program).unwrap();
+
+        // Ensure a `credits.aleo/transfer_public` call is a credits transfer.
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let inputs = [recipient.to_string(), "1_u64".to_string()];
+        let transaction = sample_execution_transaction(&process, "credits.aleo", "transfer_public", &inputs, rng);
+        assert_eq!(transaction.category(), TransactionCategory::CreditsTransfer);
+
+        // Ensure a call to any other program is a program call.
+        let inputs = ["1u32".to_string()];
+        let transaction = sample_execution_transaction(&process, "category_test.aleo", "compute", &inputs, rng);
+        assert_eq!(transaction.category(), TransactionCategory::ProgramCall);
+
+        // Ensure an empty execution is a program call, and does not panic.
+        let transaction = Transaction::<CurrentNetwork>::Execute(Default::default(), Execution::new(), None);
+        assert_eq!(transaction.category(), TransactionCategory::ProgramCall);
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use digest::{Digest, Output as DigestOutput};
+
+impl<N: Network> Transaction<N> {
+    /// The domain-separation prefix of the commitment hash.
+    pub const COMMITMENT_DOMAIN: &'static [u8] = b"snarkVM.Transaction.commitment.v1";
+
+    /// Returns a new hasher, seeded with `Transaction::COMMITMENT_DOMAIN`.
+    ///
+    /// Feeding the canonical bytes of a transaction into this hasher (in one or more chunks)
+    /// and finalizing it yields the commitment hash of the transaction.
+    pub fn commitment_hasher<H: Digest>() -> H {
+        let mut hasher = H::new();
+        hasher.update(Self::COMMITMENT_DOMAIN);
+        hasher
+    }
+
+    /// Returns the commitment hash of the transaction, as `H(COMMITMENT_DOMAIN || bytes)`,
+    /// where `bytes` are the canonical bytes of the transaction.
+    /// Note: The canonical bytes are streamed into the hasher, without being buffered.
+    pub fn commitment_hash<H: Digest>(&self) -> Result<DigestOutput<H>> {
+        let mut hasher = Self::commitment_hasher::<H>();
+        self.write_le(HashWriter(&mut hasher))?;
+        Ok(hasher.finalize())
+    }
+}
+
+/// A writer that feeds the written bytes into a hasher.
+struct HashWriter<'a, H: Digest>(&'a mut H);
+
+impl<H: Digest> Write for HashWriter<'_, H> {
+    /// Feeds the given bytes into the hasher.
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    /// Flushes the writer, which is a no-op.
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::Sha256;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_commitment_hash() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let expected = transaction.commitment_hash::<Sha256>().unwrap();
+            let bytes = transaction.to_bytes_le().unwrap();
+
+            // Ensure the commitment hash is reproducible from the bytes alone.
+            let mut preimage = Transaction::<CurrentNetwork>::COMMITMENT_DOMAIN.to_vec();
+            preimage.extend(&bytes);
+            assert_eq!(expected, Sha256::digest(preimage));
+
+            // Ensure the commitment hash is reproducible from the bytes, as they stream in.
+            let mut hasher = Transaction::<CurrentNetwork>::commitment_hasher::<Sha256>();
+            for chunk in bytes.chunks(1000) {
+                hasher.update(chunk);
+            }
+            assert_eq!(expected, hasher.finalize());
+
+            // Ensure the commitment hash is domain-separated.
+            assert_ne!(expected, Sha256::digest(bytes));
+        }
+    }
+}
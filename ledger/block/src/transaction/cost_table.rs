@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A table of costs (in microcredits), used to compute the minimum fee of a transaction.
+///
+/// The minimum fee is computed as:
+/// `per_byte * num_bytes + per_transition * num_transitions + per_finalize * num_finalizes`, where
+/// `num_bytes` is the size of the deployment or execution, `num_transitions` is the number of transitions
+/// in the execution, and `num_finalizes` is the number of those transitions that output a future.
+/// Note: The fee transition is not counted, and a fee transaction has a minimum fee of zero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CostTable {
+    /// The cost per byte of the deployment or execution.
+    per_byte: u64,
+    /// The cost per transition in the execution.
+    per_transition: u64,
+    /// The cost per transition in the execution that outputs a future.
+    per_finalize: u64,
+}
+
+impl CostTable {
+    /// Initializes a new cost table.
+    pub const fn new(per_byte: u64, per_transition: u64, per_finalize: u64) -> Self {
+        Self { per_byte, per_transition, per_finalize }
+    }
+
+    /// Returns the cost per byte of the deployment or execution.
+    pub const fn per_byte(&self) -> u64 {
+        self.per_byte
+    }
+
+    /// Returns the cost per transition in the execution.
+    pub const fn per_transition(&self) -> u64 {
+        self.per_transition
+    }
+
+    /// Returns the cost per transition in the execution that outputs a future.
+    pub const fn per_finalize(&self) -> u64 {
+        self.per_finalize
+    }
+
+    /// Returns the minimum fee (in microcredits) of the given transaction, according to this table.
+    pub fn minimum_fee<N: Network>(&self, transaction: &Transaction<N>) -> Result<u64> {
+        // Determine the number of bytes, transitions, and finalizes.
+        let (num_bytes, num_transitions, num_finalizes) = match transaction {
+            Transaction::Deploy(_, _, deployment, _) => (deployment.size_in_bytes()?, 0, 0),
+            Transaction::Execute(_, execution, _) => {
+                let num_finalizes = execution
+                    .transitions()
+                    .filter(|transition| transition.outputs().iter().any(|output| output.future().is_some()))
+                    .count();
+                (execution.size_in_bytes()?, execution.len() as u64, num_finalizes as u64)
+            }
+            Transaction::Fee(..) => (0, 0, 0),
+        };
+        // Compute the minimum fee.
+        self.per_byte
+            .checked_mul(num_bytes)
+            .zip(self.per_transition.checked_mul(num_transitions))
+            .zip(self.per_finalize.checked_mul(num_finalizes))
+            .and_then(|((bytes, transitions), finalizes)| bytes.checked_add(transitions)?.checked_add(finalizes))
+            .ok_or_else(|| anyhow!("The minimum fee computation overflowed for transaction '{}'", transaction.id()))
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Ensures the base fee of the transaction is at least the minimum fee computed from the given cost table.
+    /// See `CostTable` for the cost formula.
+    pub fn verify_fee_with_table(&self, table: &CostTable) -> Result<()> {
+        // Compute the minimum fee.
+        let minimum_fee = table.minimum_fee(self)?;
+        // Retrieve the base fee.
+        let base_fee = *self.base_fee_amount()?;
+        // Ensure the base fee is sufficient.
+        ensure!(
+            base_fee >= minimum_fee,
+            "Transaction '{}' has a base fee of {base_fee} microcredits, below the minimum of {minimum_fee}",
+            self.id()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_fee_with_table() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let base_fee = *transaction.base_fee_amount().unwrap();
+
+            // Ensure a free table is satisfied.
+            let table = CostTable::new(0, 0, 0);
+            assert_eq!(table.minimum_fee(&transaction).unwrap(), 0);
+            transaction.verify_fee_with_table(&table).unwrap();
+
+            // Ensure the fee is checked against the per-byte cost.
+            let num_bytes = match &transaction {
+                Transaction::Deploy(_, _, deployment, _) => deployment.size_in_bytes().unwrap(),
+                Transaction::Execute(_, execution, _) => execution.size_in_bytes().unwrap(),
+                Transaction::Fee(..) => continue,
+            };
+            let table = CostTable::new(1, 0, 0);
+            assert_eq!(table.minimum_fee(&transaction).unwrap(), num_bytes);
+            assert_eq!(transaction.verify_fee_with_table(&table).is_ok(), base_fee >= num_bytes);
+            // Ensure a table exceeding the base fee is rejected.
+            let table = CostTable::new(base_fee / num_bytes + 1, 0, 0);
+            assert!(transaction.verify_fee_with_table(&table).is_err());
+            // Ensure an overflowing table is rejected.
+            let table = CostTable::new(u64::MAX, u64::MAX, u64::MAX);
+            assert!(transaction.verify_fee_with_table(&table).is_err());
+        }
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The flag of a delta component that is absent.
+const DELTA_ABSENT: u8 = 0;
+/// The flag of a delta component that is written in full.
+const DELTA_PRESENT: u8 = 1;
+/// The flag of a delta component that is omitted, as it is unchanged from the baseline.
+const DELTA_UNCHANGED: u8 = 2;
+
+impl<N: Network> Transaction<N> {
+    /// Writes the transaction to the buffer, omitting each component that is unchanged from the given baseline.
+    ///
+    /// The components are the owner, deployment, and fee of a deploy transaction, the execution and fee
+    /// of an execute transaction, and the fee of a fee transaction. A component is only omitted if the
+    /// baseline is of the same variant. The result is read with `Transaction::read_le_delta`.
+    pub fn write_le_delta<W: Write>(&self, baseline: &Self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the transaction.
+        match self {
+            Self::Deploy(id, owner, deployment, fee) => {
+                // Retrieve the baseline components.
+                let (base_owner, base_deployment, base_fee) = match baseline {
+                    Self::Deploy(_, owner, deployment, fee) => (Some(owner), Some(&**deployment), Some(fee)),
+                    _ => (None, None, None),
+                };
+                // Write the variant.
+                0u8.write_le(&mut writer)?;
+                // Write the ID.
+                id.write_le(&mut writer)?;
+                // Write the components.
+                write_delta_component(Some(owner), base_owner, &mut writer)?;
+                write_delta_component(Some(&**deployment), base_deployment, &mut writer)?;
+                write_delta_component(Some(fee), base_fee, &mut writer)
+            }
+            Self::Execute(id, execution, fee) => {
+                // Retrieve the baseline components.
+                let (base_execution, base_fee) = match baseline {
+                    Self::Execute(_, execution, fee) => (Some(execution), fee.as_ref()),
+                    _ => (None, None),
+                };
+                // Write the variant.
+                1u8.write_le(&mut writer)?;
+                // Write the ID.
+                id.write_le(&mut writer)?;
+                // Write the components.
+                write_delta_component(Some(execution), base_execution, &mut writer)?;
+                write_delta_component(fee.as_ref(), base_fee, &mut writer)
+            }
+            Self::Fee(id, fee) => {
+                // Retrieve the baseline components.
+                let base_fee = match baseline {
+                    Self::Fee(_, fee) => Some(fee),
+                    _ => None,
+                };
+                // Write the variant.
+                2u8.write_le(&mut writer)?;
+                // Write the ID.
+                id.write_le(&mut writer)?;
+                // Write the components.
+                write_delta_component(Some(fee), base_fee, &mut writer)
+            }
+        }
+    }
+
+    /// Reads a transaction written by `Transaction::write_le_delta`, filling the omitted components from the
+    /// given baseline. Returns an error if the reconstructed transaction ID does not match the written ID.
+    pub fn read_le_delta<R: Read>(baseline: &Self, mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid transaction delta version"));
+        }
+
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Match the variant.
+        let (id, transaction) = match variant {
+            0 => {
+                // Retrieve the baseline components.
+                let (base_owner, base_deployment, base_fee) = match baseline {
+                    Self::Deploy(_, owner, deployment, fee) => (Some(owner), Some(&**deployment), Some(fee)),
+                    _ => (None, None, None),
+                };
+                // Read the ID.
+                let id = N::TransactionID::read_le(&mut reader)?;
+                // Read the components.
+                let owner = required(read_delta_component(base_owner, &mut reader)?, "owner")?;
+                let deployment = required(read_delta_component(base_deployment, &mut reader)?, "deployment")?;
+                let fee = required(read_delta_component(base_fee, &mut reader)?, "fee")?;
+
+                // Initialize the transaction.
+                let transaction = Self::from_deployment(owner, deployment, fee).map_err(|e| error(e.to_string()))?;
+                // Return the ID and the transaction.
+                (id, transaction)
+            }
+            1 => {
+                // Retrieve the baseline components.
+                let (base_execution, base_fee) = match baseline {
+                    Self::Execute(_, execution, fee) => (Some(execution), fee.as_ref()),
+                    _ => (None, None),
+                };
+                // Read the ID.
+                let id = N::TransactionID::read_le(&mut reader)?;
+                // Read the components.
+                let execution = required(read_delta_component(base_execution, &mut reader)?, "execution")?;
+                let fee = read_delta_component(base_fee, &mut reader)?;
+
+                // Initialize the transaction.
+                let transaction = Self::from_execution(execution, fee).map_err(|e| error(e.to_string()))?;
+                // Return the ID and the transaction.
+                (id, transaction)
+            }
+            2 => {
+                // Retrieve the baseline components.
+                let base_fee = match baseline {
+                    Self::Fee(_, fee) => Some(fee),
+                    _ => None,
+                };
+                // Read the ID.
+                let id = N::TransactionID::read_le(&mut reader)?;
+                // Read the components.
+                let fee = required(read_delta_component(base_fee, &mut reader)?, "fee")?;
+
+                // Initialize the transaction.
+                let transaction = Self::from_fee(fee).map_err(|e| error(e.to_string()))?;
+                // Return the ID and the transaction.
+                (id, transaction)
+            }
+            3.. => return Err(error("Invalid transaction variant")),
+        };
+
+        // Ensure the transaction ID matches.
+        match transaction.id() == id {
+            // Return the transaction.
+            true => Ok(transaction),
+            false => Err(error("Transaction ID mismatch")),
+        }
+    }
+}
+
+/// Writes the given component, omitting it if it is unchanged from the given baseline component.
+fn write_delta_component<T: ToBytes + PartialEq, W: Write>(
+    component: Option<&T>,
+    baseline: Option<&T>,
+    mut writer: W,
+) -> IoResult<()> {
+    match component {
+        None => DELTA_ABSENT.write_le(&mut writer),
+        Some(component) if Some(component) == baseline => DELTA_UNCHANGED.write_le(&mut writer),
+        Some(component) => {
+            DELTA_PRESENT.write_le(&mut writer)?;
+            component.write_le(&mut writer)
+        }
+    }
+}
+
+/// Reads a component, filling it from the given baseline component if it was omitted.
+fn read_delta_component<T: FromBytes + Clone, R: Read>(baseline: Option<&T>, mut reader: R) -> IoResult<Option<T>> {
+    match u8::read_le(&mut reader)? {
+        DELTA_ABSENT => Ok(None),
+        DELTA_PRESENT => Ok(Some(T::read_le(&mut reader)?)),
+        DELTA_UNCHANGED => match baseline {
+            Some(baseline) => Ok(Some(baseline.clone())),
+            None => Err(error("Missing the baseline of an unchanged transaction component")),
+        },
+        _ => Err(error("Invalid transaction delta component flag")),
+    }
+}
+
+/// Returns the given component, or an error if it is absent.
+fn required<T>(component: Option<T>, name: &str) -> IoResult<T> {
+    component.ok_or_else(|| error(format!("Missing the transaction {name}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Returns the delta bytes of the given transaction against the given baseline.
+    fn delta_bytes(transaction: &Transaction<CurrentNetwork>, baseline: &Transaction<CurrentNetwork>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        transaction.write_le_delta(baseline, &mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_delta() {
+        let rng = &mut TestRng::default();
+
+        let transactions = [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ];
+
+        for transaction in &transactions {
+            for baseline in &transactions {
+                // Ensure the transaction round-trips against the baseline.
+                let bytes = delta_bytes(transaction, baseline);
+                assert_eq!(transaction, &Transaction::read_le_delta(baseline, &bytes[..]).unwrap());
+                // Ensure the delta is never larger than the full encoding, plus the component flags.
+                assert!(bytes.len() <= transaction.to_bytes_le().unwrap().len() + 3);
+            }
+            // Ensure the delta against itself omits every component.
+            assert!(delta_bytes(transaction, transaction).len() < 64);
+        }
+
+        // Ensure a deployment that is unchanged from the baseline is omitted.
+        let (transaction, baseline) = (&transactions[1], &transactions[0]);
+        assert_eq!(transaction.deployment(), baseline.deployment());
+        let deployment_size = transaction.deployment().unwrap().to_bytes_le().unwrap().len();
+        let bytes = delta_bytes(transaction, baseline);
+        assert!(bytes.len() + deployment_size <= transaction.to_bytes_le().unwrap().len() + 3);
+
+        // Ensure a delta is rejected against a baseline that lacks the omitted components.
+        let bytes = delta_bytes(transaction, baseline);
+        assert!(Transaction::read_le_delta(&transactions[2], &bytes[..]).is_err());
+    }
+}
@@ -13,16 +13,50 @@
 // limitations under the License.
 
 use super::*;
+use crate::transaction::bytes::CountingReader;
 
 impl<N: Network> FromBytes for Deployment<N> {
     /// Reads the deployment from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_internal(reader, None).map_err(|err| error(format!("{err}")))
+    }
+}
+
+/// The error returned when the verifying keys of a deployment exceed the maximum number of bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKeysTooLarge {
+    /// The maximum number of bytes in the verifying keys.
+    pub max_bytes: usize,
+    /// The number of bytes read when the maximum was exceeded.
+    /// Note: This is a lower bound, as the remaining verifying keys are not read.
+    pub num_bytes: usize,
+}
+
+impl Display for VerifyingKeysTooLarge {
+    /// Formats the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Deployment verifying keys exceed the maximum of {} bytes (read {})", self.max_bytes, self.num_bytes)
+    }
+}
+
+impl std::error::Error for VerifyingKeysTooLarge {}
+
+impl<N: Network> Deployment<N> {
+    /// Reads the deployment from a buffer, ensuring its verifying keys do not exceed `max_verifying_key_bytes` bytes.
+    /// If they do, the read stops at the first verifying key over the limit, and a `VerifyingKeysTooLarge` error
+    /// is returned.
+    ///
+    /// Note: The limit is only enforced by this method, and `Deployment::read_le` accepts deployments of any size.
+    pub fn read_le_with_max_verifying_key_bytes<R: Read>(reader: R, max_verifying_key_bytes: usize) -> Result<Self> {
+        Self::read_le_internal(reader, Some(max_verifying_key_bytes))
+    }
+
+    /// Reads the deployment from a buffer, ensuring its verifying keys do not exceed the given number of bytes, if any.
+    fn read_le_internal<R: Read>(mut reader: R, max_verifying_key_bytes: Option<usize>) -> Result<Self> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid deployment version"));
-        }
+        ensure!(version == 1, "Invalid deployment version");
 
         // Read the edition.
         let edition = u16::read_le(&mut reader)?;
@@ -33,11 +67,21 @@ impl<N: Network> FromBytes for Deployment<N> {
         let num_entries = u16::read_le(&mut reader)?;
         // Read the verifying keys.
         let mut verifying_keys = Vec::with_capacity(num_entries as usize);
+        // Initialize a counter for the number of bytes in the verifying keys.
+        let mut num_verifying_key_bytes = 0usize;
         for _ in 0..num_entries {
             // Read the identifier.
             let identifier = Identifier::<N>::read_le(&mut reader)?;
-            // Read the verifying key.
-            let verifying_key = VerifyingKey::<N>::read_le(&mut reader)?;
+            // Read the verifying key, counting the bytes it consumes.
+            let mut counting_reader = CountingReader::new(&mut reader, |_: &[u8]| ());
+            let verifying_key = VerifyingKey::<N>::read_le(&mut counting_reader)?;
+            num_verifying_key_bytes = num_verifying_key_bytes.saturating_add(counting_reader.consumed());
+            // Ensure the verifying keys do not exceed the maximum number of bytes.
+            if let Some(max_bytes) = max_verifying_key_bytes {
+                if num_verifying_key_bytes > max_bytes {
+                    return Err(VerifyingKeysTooLarge { max_bytes, num_bytes: num_verifying_key_bytes }.into());
+                }
+            }
             // Read the certificate.
             let certificate = Certificate::<N>::read_le(&mut reader)?;
             // Add the entry.
@@ -45,7 +89,7 @@ impl<N: Network> FromBytes for Deployment<N> {
         }
 
         // Return the deployment.
-        Self::new(edition, program, verifying_keys).map_err(|err| error(format!("{err}")))
+        Self::new(edition, program, verifying_keys)
     }
 }
 
@@ -77,6 +121,8 @@ impl<N: Network> ToBytes for Deployment<N> {
 mod tests {
     use super::*;
 
+    type CurrentNetwork = console::network::MainnetV0;
+
     #[test]
     fn test_bytes() -> Result<()> {
         let rng = &mut TestRng::default();
@@ -89,4 +135,34 @@ mod tests {
         assert_eq!(expected, Deployment::read_le(&expected_bytes[..])?);
         Ok(())
     }
+
+    #[test]
+    fn test_read_le_with_max_verifying_key_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new deployment.
+        let expected = test_helpers::sample_deployment(rng);
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Compute the number of bytes in the verifying keys.
+        let mut num_verifying_key_bytes = 0;
+        for (_, (verifying_key, _)) in expected.verifying_keys() {
+            num_verifying_key_bytes += verifying_key.to_bytes_le()?.len();
+        }
+
+        // Ensure the deployment is read when the verifying keys are within the limit.
+        let candidate = Deployment::read_le_with_max_verifying_key_bytes(&expected_bytes[..], num_verifying_key_bytes)?;
+        assert_eq!(expected, candidate);
+        // Ensure the deployment is rejected with a typed error when the verifying keys exceed the limit.
+        let max_bytes = num_verifying_key_bytes - 1;
+        let error = Deployment::<CurrentNetwork>::read_le_with_max_verifying_key_bytes(&expected_bytes[..], max_bytes)
+            .unwrap_err();
+        let error = error.downcast_ref::<VerifyingKeysTooLarge>().unwrap();
+        assert_eq!(error.max_bytes, max_bytes);
+        assert!(error.num_bytes > max_bytes && error.num_bytes <= num_verifying_key_bytes);
+        // Ensure the default reader is not limited.
+        assert_eq!(expected, Deployment::read_le_with_max_verifying_key_bytes(&expected_bytes[..], usize::MAX)?);
+        assert_eq!(expected, Deployment::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
 }
@@ -15,6 +15,8 @@
 #![allow(clippy::type_complexity)]
 
 mod bytes;
+pub use bytes::*;
+
 mod serialize;
 mod string;
 
@@ -114,7 +116,9 @@ impl<N: Network> Deployment<N> {
         &self.program
     }
 
-    /// Returns the program.
+    /// Returns the program ID.
+    /// Note: The program ID is only encoded in the program, so a deploy transaction
+    /// cannot claim a program ID that differs from the one in its deployment.
     pub const fn program_id(&self) -> &ProgramID<N> {
         self.program.id()
     }
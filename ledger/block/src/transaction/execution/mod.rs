@@ -14,6 +14,8 @@
 
 mod bytes;
 mod serialize;
+mod size_tracker;
+pub use size_tracker::*;
 mod string;
 
 use crate::{Transaction, Transition};
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::marker::PhantomData;
+
+/// A running total of the serialized size of an execution, as its transitions are appended.
+///
+/// The size is that of the execution *without* a proof, as the proof is only known once the execution is proven.
+/// Callers that must account for the proof (and the surrounding transaction) should reserve for it in the limit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionSizeTracker<N: Network> {
+    /// The maximum size in bytes.
+    limit: usize,
+    /// The current size in bytes.
+    size: usize,
+    /// The number of transitions.
+    num_transitions: usize,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> ExecutionSizeTracker<N> {
+    /// Initializes a new size tracker, with a limit of `N::MAX_TRANSACTION_SIZE` bytes.
+    pub fn new() -> Result<Self> {
+        Self::with_limit(N::MAX_TRANSACTION_SIZE)
+    }
+
+    /// Initializes a new size tracker, with the given limit in bytes.
+    pub fn with_limit(limit: usize) -> Result<Self> {
+        // Compute the size of an empty execution, which accounts for the version, the number of transitions,
+        // the global state root, and the proof variant.
+        let size = Execution::<N>::new().to_bytes_le()?.len();
+        // Ensure the empty execution fits within the limit.
+        ensure!(size <= limit, "The size limit ({limit} bytes) is smaller than an empty execution ({size} bytes)");
+        // Return the size tracker.
+        Ok(Self { limit, size, num_transitions: 0, _phantom: PhantomData })
+    }
+
+    /// Returns the maximum size in bytes.
+    pub const fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the current size in bytes.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of transitions.
+    pub const fn len(&self) -> usize {
+        self.num_transitions
+    }
+
+    /// Returns `true` if no transitions have been appended.
+    pub const fn is_empty(&self) -> bool {
+        self.num_transitions == 0
+    }
+
+    /// Returns `true` if the given transition can be appended without exceeding the size limit,
+    /// or the maximum number of transitions.
+    pub fn would_fit(&self, additional: &Transition<N>) -> bool {
+        match additional.to_bytes_le() {
+            Ok(bytes) => self.would_fit_size(bytes.len()),
+            Err(_) => false,
+        }
+    }
+
+    /// Appends the size of the given transition.
+    /// Returns an error if the transition does not fit, in which case the tracker is unchanged.
+    pub fn push(&mut self, transition: &Transition<N>) -> Result<()> {
+        // Compute the size of the transition.
+        let transition_size = transition.to_bytes_le()?.len();
+        // Ensure the transition fits.
+        ensure!(
+            self.would_fit_size(transition_size),
+            "Transition '{}' ({transition_size} bytes) does not fit in the execution ({} of {} bytes, {} transitions)",
+            transition.id(),
+            self.size,
+            self.limit,
+            self.num_transitions
+        );
+        // Update the size and number of transitions.
+        self.size += transition_size;
+        self.num_transitions += 1;
+        Ok(())
+    }
+
+    /// Returns `true` if a transition of the given size can be appended.
+    fn would_fit_size(&self, transition_size: usize) -> bool {
+        // Note: Observe we hold back 1 transition for the fee.
+        self.num_transitions + 1 < Transaction::<N>::MAX_TRANSITIONS
+            && self.size.saturating_add(transition_size) <= self.limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_size_tracker() {
+        let rng = &mut TestRng::default();
+
+        // Construct a new execution, without a proof.
+        let execution = crate::transaction::execution::test_helpers::sample_execution(rng);
+        let global_state_root = execution.global_state_root();
+        let execution = Execution::from(execution.into_transitions(), global_state_root, None).unwrap();
+
+        // Ensure the tracked size matches the size of the execution.
+        let mut tracker = ExecutionSizeTracker::<CurrentNetwork>::new().unwrap();
+        assert!(tracker.is_empty());
+        for transition in execution.transitions() {
+            assert!(tracker.would_fit(transition));
+            tracker.push(transition).unwrap();
+        }
+        assert_eq!(tracker.len(), execution.len());
+        assert_eq!(tracker.size_in_bytes() as u64, execution.size_in_bytes().unwrap());
+
+        // Ensure a transition that exceeds the limit is rejected.
+        let transition = execution.peek().unwrap();
+        let empty_size = ExecutionSizeTracker::<CurrentNetwork>::new().unwrap().size_in_bytes();
+        let limit = empty_size + transition.to_bytes_le().unwrap().len() - 1;
+        let mut tracker = ExecutionSizeTracker::<CurrentNetwork>::with_limit(limit).unwrap();
+        assert!(!tracker.would_fit(transition));
+        assert!(tracker.push(transition).is_err());
+        assert!(tracker.is_empty());
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::{Argument, Future, Plaintext};
+
+/// The error returned when the finalize operation of a transition is malformed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MalformedFinalize<N: Network> {
+    /// The ID of the transition with the malformed finalize operation.
+    pub transition_id: N::TransitionID,
+    /// The reason the finalize operation is malformed.
+    pub reason: String,
+}
+
+impl<N: Network> Display for MalformedFinalize<N> {
+    /// Formats the malformed finalize error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "The finalize operation of transition '{}' is malformed - {}", self.transition_id, self.reason)
+    }
+}
+
+impl<N: Network> std::error::Error for MalformedFinalize<N> {}
+
+impl<N: Network> Transaction<N> {
+    /// Ensures the finalize operations of the transaction are structurally well-formed, without running them.
+    ///
+    /// The finalize operation of a transition is the future in its last output. For each transition (including
+    /// the fee), this checks that only the last output is a future, that the future matches the transition's
+    /// program ID and function name, and that its arguments (and any nested futures) are within the network bounds.
+    /// On failure, a `MalformedFinalize` error is returned with the ID of the offending transition.
+    ///
+    /// Note: This does not check the arguments against the finalize types of the program,
+    /// nor the state of any mappings, as these require the process and the finalize store.
+    pub fn verify_finalize_preconditions(&self) -> Result<()> {
+        for transition in self.transitions() {
+            if let Err(reason) = check_transition_finalize(transition) {
+                return Err(MalformedFinalize::<N> { transition_id: *transition.id(), reason }.into());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checks the finalize operation of the given transition, returning the reason on failure.
+fn check_transition_finalize<N: Network>(transition: &Transition<N>) -> Result<(), String> {
+    // Ensure only the last output is a future.
+    let num_outputs = transition.outputs().len();
+    for (index, output) in transition.outputs().iter().enumerate() {
+        if matches!(output, Output::Future(..)) && index + 1 != num_outputs {
+            return Err(format!("output {index} is a future, but is not the last output"));
+        }
+    }
+    // Retrieve the future, if it is present.
+    let future = match transition.outputs().last().and_then(|output| output.future()) {
+        Some(future) => future,
+        None => return Ok(()),
+    };
+    // Ensure the program ID and function name of the future match the transition.
+    if future.program_id() != transition.program_id() || future.function_name() != transition.function_name() {
+        return Err(format!(
+            "the future '{}/{}' does not match the transition",
+            future.program_id(),
+            future.function_name()
+        ));
+    }
+    // Ensure the arguments are within bounds.
+    check_future::<N>(future, 0)
+}
+
+/// Checks the arguments of the given future are within bounds, returning the reason on failure.
+fn check_future<N: Network>(future: &Future<N>, depth: usize) -> Result<(), String> {
+    // Ensure the future does not exceed the maximum depth.
+    if depth > N::MAX_DATA_DEPTH {
+        let (program_id, function_name) = (future.program_id(), future.function_name());
+        return Err(format!("the future '{program_id}/{function_name}' exceeds the maximum depth"));
+    }
+    // Ensure the number of arguments is within bounds.
+    if future.arguments().len() > N::MAX_INPUTS {
+        return Err(format!(
+            "the future '{}/{}' has {} arguments, exceeding the maximum of {}",
+            future.program_id(),
+            future.function_name(),
+            future.arguments().len(),
+            N::MAX_INPUTS
+        ));
+    }
+    // Ensure each argument is within bounds.
+    for (index, argument) in future.arguments().iter().enumerate() {
+        let result = match argument {
+            Argument::Plaintext(plaintext) => check_plaintext::<N>(plaintext, 0),
+            Argument::Future(future) => check_future::<N>(future, depth + 1),
+        };
+        result.map_err(|reason| {
+            format!("argument {index} of '{}/{}': {reason}", future.program_id(), future.function_name())
+        })?;
+    }
+    Ok(())
+}
+
+/// Checks the given plaintext is within bounds, returning the reason on failure.
+fn check_plaintext<N: Network>(plaintext: &Plaintext<N>, depth: usize) -> Result<(), String> {
+    // Ensure the plaintext does not exceed the maximum depth.
+    if depth > N::MAX_DATA_DEPTH {
+        return Err(format!("the value exceeds the maximum depth of {}", N::MAX_DATA_DEPTH));
+    }
+    match plaintext {
+        Plaintext::Literal(..) => Ok(()),
+        Plaintext::Struct(members, _) => match members.len() <= N::MAX_STRUCT_ENTRIES {
+            true => members.values().try_for_each(|member| check_plaintext::<N>(member, depth + 1)),
+            false => Err(format!(
+                "the struct has {} members, exceeding the maximum of {}",
+                members.len(),
+                N::MAX_STRUCT_ENTRIES
+            )),
+        },
+        Plaintext::Array(elements, _) => match elements.len() <= N::MAX_ARRAY_ELEMENTS {
+            true => elements.iter().try_for_each(|element| check_plaintext::<N>(element, depth + 1)),
+            false => Err(format!(
+                "the array has {} elements, exceeding the maximum of {}",
+                elements.len(),
+                N::MAX_ARRAY_ELEMENTS
+            )),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::program::{Identifier, Literal};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Returns the given transition, with its last output replaced by the given future.
+    fn with_future(
+        transition: &Transition<CurrentNetwork>,
+        future: Future<CurrentNetwork>,
+    ) -> Transition<CurrentNetwork> {
+        let mut outputs = transition.outputs().to_vec();
+        *outputs.last_mut().unwrap() = Output::Future(Field::from_u8(1), Some(future));
+        Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            outputs,
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.scm(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify_finalize_preconditions() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            transaction.verify_finalize_preconditions().unwrap();
+        }
+
+        // Retrieve a transition with a future.
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        let transition = transaction.fee_transition().unwrap().transition().clone();
+        let future = transition.outputs().last().unwrap().future().unwrap().clone();
+        let program_id = *future.program_id();
+        // Returns a feeless execution transaction with the given transition.
+        let global_state_root = transaction.fee_transition().unwrap().global_state_root();
+        let sample_transaction = |transition| {
+            crate::transaction::test_helpers::sample_execution_transaction_with_transition(
+                transition,
+                global_state_root,
+            )
+        };
+
+        // Ensure a future that does not match the transition is rejected.
+        let invalid = with_future(&transition, Future::new(program_id, Identifier::from_str("foo").unwrap(), vec![]));
+        let error = sample_transaction(invalid.clone()).verify_finalize_preconditions().unwrap_err();
+        assert_eq!(error.downcast_ref::<MalformedFinalize<CurrentNetwork>>().unwrap().transition_id, *invalid.id());
+
+        // Ensure a future with too many arguments is rejected.
+        let argument = Argument::Plaintext(Plaintext::from(Literal::U8(console::types::U8::new(1))));
+        let arguments = vec![argument; CurrentNetwork::MAX_INPUTS + 1];
+        let invalid = with_future(&transition, Future::new(program_id, *future.function_name(), arguments));
+        let error = sample_transaction(invalid.clone()).verify_finalize_preconditions().unwrap_err();
+        assert_eq!(error.downcast_ref::<MalformedFinalize<CurrentNetwork>>().unwrap().transition_id, *invalid.id());
+
+        // Ensure a valid future passes.
+        let valid = with_future(&transition, future);
+        sample_transaction(valid).verify_finalize_preconditions().unwrap();
+    }
+}
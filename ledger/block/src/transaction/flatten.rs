@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::Input;
+
+/// The direction of a transition input or output.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IoDirection {
+    /// A transition input.
+    Input,
+    /// A transition output.
+    Output,
+}
+
+impl IoDirection {
+    /// Returns the direction as a string.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Input => "input",
+            Self::Output => "output",
+        }
+    }
+}
+
+/// The visibility of a transition input or output.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IoVisibility {
+    /// The value is a constant.
+    Constant,
+    /// The value is public.
+    Public,
+    /// The value is private.
+    Private,
+}
+
+impl IoVisibility {
+    /// Returns the visibility as a string.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Constant => "constant",
+            Self::Public => "public",
+            Self::Private => "private",
+        }
+    }
+}
+
+/// A row of the flattened inputs and outputs of a transaction.
+///
+/// The `variant` is the `type` of the input or output, as in its JSON serialization (e.g. `record`),
+/// the `id` is its input or output ID (e.g. the hash, serial number, or commitment),
+/// and the `value` is its plaintext, ciphertext, record, or future, if it is present.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IoRecord<N: Network> {
+    /// The index of the transition, in the order of `Transaction::transitions`.
+    pub transition_index: usize,
+    /// The index of the input or output, within the transition.
+    pub io_index: usize,
+    /// The direction.
+    pub direction: IoDirection,
+    /// The visibility.
+    pub visibility: IoVisibility,
+    /// The type of the input or output.
+    pub variant: &'static str,
+    /// The input or output ID.
+    pub id: Field<N>,
+    /// The value, if it is present.
+    pub value: Option<String>,
+}
+
+impl<N: Network> Serialize for IoRecord<N> {
+    /// Serializes the flattened input or output into a struct.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut record = serializer.serialize_struct("IoRecord", 6 + self.value.is_some() as usize)?;
+        record.serialize_field("transition_index", &self.transition_index)?;
+        record.serialize_field("io_index", &self.io_index)?;
+        record.serialize_field("direction", self.direction.as_str())?;
+        record.serialize_field("visibility", self.visibility.as_str())?;
+        record.serialize_field("type", self.variant)?;
+        record.serialize_field("id", &self.id)?;
+        if let Some(value) = &self.value {
+            record.serialize_field("value", value)?;
+        }
+        record.end()
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns one row for each input and output of each transition (including the fee), in order,
+    /// where the inputs of a transition precede its outputs.
+    pub fn flatten_io(&self) -> Vec<IoRecord<N>> {
+        let mut records = Vec::new();
+        for (transition_index, transition) in self.transitions().enumerate() {
+            for (io_index, input) in transition.inputs().iter().enumerate() {
+                let (visibility, variant, id, value) = match input {
+                    Input::Constant(id, value) => {
+                        (IoVisibility::Constant, "constant", id, value.as_ref().map(to_string))
+                    }
+                    Input::Public(id, value) => (IoVisibility::Public, "public", id, value.as_ref().map(to_string)),
+                    Input::Private(id, value) => (IoVisibility::Private, "private", id, value.as_ref().map(to_string)),
+                    Input::Record(serial_number, _) => (IoVisibility::Private, "record", serial_number, None),
+                    Input::ExternalRecord(id) => (IoVisibility::Private, "external_record", id, None),
+                };
+                let direction = IoDirection::Input;
+                records.push(IoRecord { transition_index, io_index, direction, visibility, variant, id: *id, value });
+            }
+            for (io_index, output) in transition.outputs().iter().enumerate() {
+                let (visibility, variant, id, value) = match output {
+                    Output::Constant(id, value) => {
+                        (IoVisibility::Constant, "constant", id, value.as_ref().map(to_string))
+                    }
+                    Output::Public(id, value) => (IoVisibility::Public, "public", id, value.as_ref().map(to_string)),
+                    Output::Private(id, value) => (IoVisibility::Private, "private", id, value.as_ref().map(to_string)),
+                    Output::Record(commitment, _, value) => {
+                        (IoVisibility::Private, "record", commitment, value.as_ref().map(to_string))
+                    }
+                    Output::ExternalRecord(id) => (IoVisibility::Private, "external_record", id, None),
+                    Output::Future(id, value) => (IoVisibility::Public, "future", id, value.as_ref().map(to_string)),
+                };
+                let direction = IoDirection::Output;
+                records.push(IoRecord { transition_index, io_index, direction, visibility, variant, id: *id, value });
+            }
+        }
+        records
+    }
+}
+
+/// Returns the given value as a string.
+fn to_string<T: Display>(value: &T) -> String {
+    value.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_io() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let records = transaction.flatten_io();
+            // Ensure there is one row per input and output.
+            let expected = transaction.transitions().map(|t| t.inputs().len() + t.outputs().len()).sum::<usize>();
+            assert_eq!(records.len(), expected);
+            // Ensure the IDs match the input and output IDs.
+            let ids = transaction.transitions().flat_map(|t| t.input_ids().chain(t.output_ids())).collect::<Vec<_>>();
+            assert_eq!(records.iter().map(|record| &record.id).collect::<Vec<_>>(), ids);
+
+            for record in &records {
+                let transition = transaction.transitions().nth(record.transition_index).unwrap();
+                // Ensure the value matches the input or output.
+                let (value, id) = match record.direction {
+                    IoDirection::Input => {
+                        let input = &transition.inputs()[record.io_index];
+                        (serde_json::to_value(input).unwrap(), input.id())
+                    }
+                    IoDirection::Output => {
+                        let output = &transition.outputs()[record.io_index];
+                        (serde_json::to_value(output).unwrap(), output.id())
+                    }
+                };
+                assert_eq!(id, &record.id);
+                assert_eq!(value["type"], record.variant);
+                if let Some(expected) = value.get("value") {
+                    assert_eq!(Some(expected.as_str().unwrap()), record.value.as_deref());
+                }
+                // Ensure the row serializes with its fields.
+                let json = serde_json::to_value(record).unwrap();
+                assert_eq!(json["transition_index"], record.transition_index);
+                assert_eq!(json["direction"], record.direction.as_str());
+                assert_eq!(json["visibility"], record.visibility.as_str());
+                assert_eq!(json["type"], record.variant);
+            }
+        }
+    }
+}
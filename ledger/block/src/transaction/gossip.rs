@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::{net::SocketAddr, sync::Arc};
+
+/// A transaction received through gossip, along with its provenance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionGossipEntry<N: Network> {
+    /// The transaction.
+    transaction: Arc<Transaction<N>>,
+    /// The peers the transaction was received from.
+    peers: IndexSet<SocketAddr>,
+    /// The UNIX timestamp (in seconds) at which the transaction was first seen.
+    first_seen: i64,
+}
+
+impl<N: Network> TransactionGossipEntry<N> {
+    /// Initializes a new gossip entry, for a transaction received from the given peer at the given timestamp.
+    pub fn new(transaction: Arc<Transaction<N>>, peer: SocketAddr, timestamp: i64) -> Self {
+        Self { transaction, peers: IndexSet::from([peer]), first_seen: timestamp }
+    }
+
+    /// Returns the transaction.
+    pub const fn transaction(&self) -> &Arc<Transaction<N>> {
+        &self.transaction
+    }
+
+    /// Returns the transaction ID.
+    pub fn id(&self) -> N::TransactionID {
+        self.transaction.id()
+    }
+
+    /// Returns the peers the transaction was received from, in the order they were first seen.
+    pub const fn peers(&self) -> &IndexSet<SocketAddr> {
+        &self.peers
+    }
+
+    /// Returns the UNIX timestamp (in seconds) at which the transaction was first seen.
+    pub const fn first_seen(&self) -> i64 {
+        self.first_seen
+    }
+
+    /// Merges the provenance of the given entry into this entry, keeping this entry's copy of the transaction.
+    /// Returns an error if the entries are for different transactions, in which case this entry is unchanged.
+    pub fn merge(&mut self, other: Self) -> Result<()> {
+        // Ensure the entries are for the same transaction.
+        ensure!(
+            self.id() == other.id(),
+            "Cannot merge the gossip entry for '{}' into the gossip entry for '{}'",
+            other.id(),
+            self.id()
+        );
+        // Merge the peers, and keep the earliest timestamp.
+        self.peers.extend(other.peers);
+        self.first_seen = self.first_seen.min(other.first_seen);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() {
+        let rng = &mut TestRng::default();
+
+        let transaction = Arc::new(crate::transaction::test_helpers::sample_fee_public_transaction(rng));
+        let (peer_a, peer_b) = ("127.0.0.1:4130".parse().unwrap(), "127.0.0.1:4131".parse().unwrap());
+
+        // Ensure the entries for the same transaction merge their provenance.
+        let mut entry = TransactionGossipEntry::new(transaction.clone(), peer_a, 20);
+        entry.merge(TransactionGossipEntry::new(transaction.clone(), peer_b, 10)).unwrap();
+        entry.merge(TransactionGossipEntry::new(transaction.clone(), peer_a, 30)).unwrap();
+        assert_eq!(entry.id(), transaction.id());
+        assert_eq!(entry.peers(), &IndexSet::from([peer_a, peer_b]));
+        assert_eq!(entry.first_seen(), 10);
+
+        // Ensure an entry for a different transaction is rejected, and leaves the entry unchanged.
+        let expected = entry.clone();
+        let other = Arc::new(crate::transaction::test_helpers::sample_private_fee_transaction(rng));
+        assert!(entry.merge(TransactionGossipEntry::new(other, "127.0.0.1:4132".parse().unwrap(), 0)).is_err());
+        assert_eq!(entry, expected);
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::ops::Range;
+use indexmap::IndexMap;
+
+/// An index of the top-level fields of an encoded transaction, which maps each field name to its byte range.
+///
+/// Each field is decoded once, when the index is computed, and is retained alongside its byte range.
+/// The field names are `id`, `owner`, `deployment`, `execution`, and `fee`, where present in the variant.
+/// Note: The index does *not* check the transaction ID, which requires computing the ID from the fields.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TransactionIndex<'a, N: Network> {
+    /// The encoded transaction.
+    bytes: &'a [u8],
+    /// The transaction variant.
+    variant: u8,
+    /// The byte range of each field, in encoding order.
+    fields: IndexMap<&'static str, Range<usize>>,
+    /// The transaction ID.
+    id: N::TransactionID,
+    /// The program owner, if the transaction is a deploy transaction.
+    owner: Option<ProgramOwner<N>>,
+    /// The deployment, if the transaction is a deploy transaction.
+    deployment: Option<Deployment<N>>,
+    /// The execution, if the transaction is an execute transaction.
+    execution: Option<Execution<N>>,
+    /// The fee, if the transaction has a fee.
+    fee: Option<Fee<N>>,
+}
+
+impl<'a, N: Network> TransactionIndex<'a, N> {
+    /// Returns the index of the transaction encoded in the given bytes.
+    /// Note: The bytes must contain exactly one transaction, as produced by `Transaction::to_bytes_le`.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        let mut reader = bytes;
+        let mut fields = IndexMap::new();
+
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        ensure!(version == 1, "Invalid transaction version");
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Ensure the variant is valid.
+        ensure!(variant <= 2, "Invalid transaction variant");
+
+        // Read and index the ID.
+        let id = read_field(bytes, &mut reader, &mut fields, "id")?;
+        // Read and index the remaining fields.
+        let (mut owner, mut deployment, mut execution, mut fee) = (None, None, None, None);
+        match variant {
+            0 => {
+                owner = Some(read_field(bytes, &mut reader, &mut fields, "owner")?);
+                deployment = Some(read_field(bytes, &mut reader, &mut fields, "deployment")?);
+                fee = Some(read_field(bytes, &mut reader, &mut fields, "fee")?);
+            }
+            1 => {
+                execution = Some(read_field(bytes, &mut reader, &mut fields, "execution")?);
+                // Read the fee variant.
+                match u8::read_le(&mut reader)? {
+                    0 => (),
+                    1 => fee = Some(read_field(bytes, &mut reader, &mut fields, "fee")?),
+                    _ => bail!("Invalid fee variant"),
+                }
+            }
+            _ => fee = Some(read_field(bytes, &mut reader, &mut fields, "fee")?),
+        }
+
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the transaction", reader.len());
+        Ok(Self { bytes, variant, fields, id, owner, deployment, execution, fee })
+    }
+
+    /// Returns the transaction variant, as `0` for deploy, `1` for execute, and `2` for fee.
+    pub const fn variant(&self) -> u8 {
+        self.variant
+    }
+
+    /// Returns the names of the fields, in encoding order.
+    pub fn field_names(&self) -> impl '_ + Iterator<Item = &'static str> {
+        self.fields.keys().copied()
+    }
+
+    /// Returns the byte range of the given field, if it exists.
+    pub fn range(&self, name: &str) -> Option<Range<usize>> {
+        self.fields.get(name).cloned()
+    }
+
+    /// Returns the bytes of the given field, if it exists.
+    pub fn field_bytes(&self, name: &str) -> Option<&'a [u8]> {
+        self.fields.get(name).map(|range| &self.bytes[range.clone()])
+    }
+
+    /// Returns the transaction ID.
+    pub const fn id(&self) -> N::TransactionID {
+        self.id
+    }
+
+    /// Returns the program owner, if the transaction is a deploy transaction.
+    pub const fn owner(&self) -> Option<&ProgramOwner<N>> {
+        self.owner.as_ref()
+    }
+
+    /// Returns the deployment, if the transaction is a deploy transaction.
+    pub const fn deployment(&self) -> Option<&Deployment<N>> {
+        self.deployment.as_ref()
+    }
+
+    /// Returns the execution, if the transaction is an execute transaction.
+    pub const fn execution(&self) -> Option<&Execution<N>> {
+        self.execution.as_ref()
+    }
+
+    /// Returns the fee, if the transaction has a fee.
+    pub const fn fee(&self) -> Option<&Fee<N>> {
+        self.fee.as_ref()
+    }
+}
+
+/// Reads a field from the given reader, and indexes its byte range within the given bytes under the given name.
+fn read_field<'a, T: FromBytes>(
+    bytes: &'a [u8],
+    reader: &mut &'a [u8],
+    fields: &mut IndexMap<&'static str, Range<usize>>,
+    name: &'static str,
+) -> Result<T> {
+    let start = bytes.len() - reader.len();
+    let value = T::read_le(&mut *reader)?;
+    fields.insert(name, start..bytes.len() - reader.len());
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_transaction_index() {
+        let rng = &mut TestRng::default();
+
+        let deployment = crate::transaction::test_helpers::sample_deployment_transaction(true, rng);
+        let execution = crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let fee = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+
+        for (expected, names) in [
+            (deployment, vec!["id", "owner", "deployment", "fee"]),
+            (execution, vec!["id", "execution", "fee"]),
+            (fee, vec!["id", "fee"]),
+        ] {
+            let bytes = expected.to_bytes_le().unwrap();
+            let index = TransactionIndex::<CurrentNetwork>::new(&bytes).unwrap();
+
+            // Ensure the fields are indexed in encoding order.
+            assert_eq!(names, index.field_names().collect::<Vec<_>>());
+            assert_eq!(index.range("id").unwrap().start, 2);
+            assert_eq!(index.range("fee").unwrap().end, bytes.len());
+            assert!(index.range("unknown").is_none());
+
+            // Ensure each field decodes to the expected value.
+            let variant = match expected {
+                Transaction::Deploy(..) => 0,
+                Transaction::Execute(..) => 1,
+                Transaction::Fee(..) => 2,
+            };
+            assert_eq!(variant, index.variant());
+            assert_eq!(expected.id(), index.id());
+            assert_eq!(expected.owner(), index.owner());
+            assert_eq!(expected.deployment(), index.deployment());
+            assert_eq!(expected.execution(), index.execution());
+            assert_eq!(expected.fee_transition(), index.fee().cloned());
+            // Ensure each field's bytes are the encoding of its value.
+            assert_eq!(index.field_bytes("id").unwrap(), index.id().to_bytes_le().unwrap());
+            if let Some(fee) = index.fee() {
+                assert_eq!(index.field_bytes("fee").unwrap(), fee.to_bytes_le().unwrap());
+            }
+
+            // Ensure trailing bytes are rejected.
+            let mut trailing = bytes.clone();
+            trailing.push(0);
+            assert!(TransactionIndex::<CurrentNetwork>::new(&trailing).is_err());
+        }
+    }
+}
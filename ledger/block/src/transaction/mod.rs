@@ -12,6 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod binding;
+pub use binding::*;
+
+mod category;
+pub use category::*;
+
+mod cost_table;
+pub use cost_table::*;
+
 mod deployment;
 pub use deployment::*;
 
@@ -21,18 +30,72 @@ pub use execution::*;
 mod fee;
 pub use fee::*;
 
+mod finalize;
+pub use finalize::*;
+
+mod flatten;
+pub use flatten::*;
+
+mod gossip;
+pub use gossip::*;
+
+mod index;
+pub use index::*;
+
+mod preview;
+pub use preview::*;
+
+mod registry;
+pub use registry::*;
+
+mod size;
+pub use size::*;
+
+mod structure;
+pub use structure::*;
+
+mod template;
+pub use template::*;
+
+mod verifying_keys;
+pub use verifying_keys::*;
+
+mod view;
+pub use view::*;
+
+mod archive;
 mod bytes;
+mod commitment;
+mod delta;
 mod merkle;
+#[cfg(any(test, feature = "test"))]
+mod placeholder;
+mod pretty;
+mod redact;
+mod replacement;
+mod roots;
 mod serialize;
 mod string;
+mod weight;
 
-use crate::Transition;
+use crate::{Output, Transition};
 use console::{
     network::prelude::*,
-    program::{Ciphertext, ProgramOwner, Record, TransactionLeaf, TransactionPath, TransactionTree, TRANSACTION_DEPTH},
-    types::{Field, Group, U64},
+    program::{
+        Ciphertext,
+        ProgramID,
+        ProgramOwner,
+        Record,
+        TransactionLeaf,
+        TransactionPath,
+        TransactionTree,
+        TRANSACTION_DEPTH,
+    },
+    types::{Address, Field, Group, U64},
 };
 
+use indexmap::IndexSet;
+
 #[derive(Clone, PartialEq, Eq)]
 pub enum Transaction<N: Network> {
     /// The deploy transaction publishes an Aleo program to the network.
@@ -77,6 +140,22 @@ impl<N: Network> Transaction<N> {
         // Construct the execution transaction.
         Ok(Self::Fee(id.into(), fee))
     }
+
+    /// Returns a new transaction with the fee replaced by the output of `f`, recomputing the transaction ID.
+    /// Note: Deploy and fee transactions must contain a fee, so `f` may not remove it for these variants.
+    pub fn map_fee<F: FnOnce(Option<Fee<N>>) -> Result<Option<Fee<N>>>>(self, f: F) -> Result<Self> {
+        match self {
+            Self::Deploy(_, owner, deployment, fee) => match f(Some(fee))? {
+                Some(fee) => Self::from_deployment(owner, *deployment, fee),
+                None => bail!("Attempted to remove the fee from a deployment transaction"),
+            },
+            Self::Execute(_, execution, fee) => Self::from_execution(execution, f(fee)?),
+            Self::Fee(_, fee) => match f(Some(fee))? {
+                Some(fee) => Self::from_fee(fee),
+                None => bail!("Attempted to remove the fee from a fee transaction"),
+            },
+        }
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -122,6 +201,12 @@ impl<N: Network> Transaction<N> {
         }
     }
 
+    /// Returns `Some(address)` of the program owner if the transaction is a deployment. Otherwise, returns `None`.
+    #[inline]
+    pub fn deployment_owner_address(&self) -> Option<Address<N>> {
+        self.owner().map(ProgramOwner::address)
+    }
+
     /// Returns `Some(deployment)` if the transaction is a deployment. Otherwise, returns `None`.
     #[inline]
     pub fn deployment(&self) -> Option<&Deployment<N>> {
@@ -139,6 +224,16 @@ impl<N: Network> Transaction<N> {
             _ => None,
         }
     }
+
+    /// Returns the program IDs imported by the deployed program, if the transaction is a deployment.
+    /// Otherwise, returns an empty list.
+    #[inline]
+    pub fn dependencies(&self) -> Vec<ProgramID<N>> {
+        match self {
+            Self::Deploy(_, _, deployment, _) => deployment.program().imports().keys().copied().collect(),
+            _ => Vec::new(),
+        }
+    }
 }
 
 /// A helper enum for iterators and consuming iterators over a transaction.
@@ -220,6 +315,16 @@ impl<N: Network> Transaction<N> {
             Self::Fee(_, fee) => Some(fee.clone()),
         }
     }
+
+    /// Returns the transition that pays the fee, if the transaction has a fee.
+    /// Note: Unlike `Transaction::fee_transition`, this borrows the transition, instead of cloning the fee.
+    pub fn fee_paying_transition(&self) -> Option<&Transition<N>> {
+        match self {
+            Self::Deploy(_, _, _, fee) => Some(fee.transition()),
+            Self::Execute(_, _, fee) => fee.as_ref().map(Fee::transition),
+            Self::Fee(_, fee) => Some(fee.transition()),
+        }
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -247,6 +352,13 @@ impl<N: Network> Transaction<N> {
     pub fn contains_commitment(&self, commitment: &Field<N>) -> bool {
         self.transitions().any(|transition| transition.contains_commitment(commitment))
     }
+
+    /// Returns `true` if the given address is in a public input or output of the transaction.
+    /// Note: This includes the payer of a public fee, which is an argument of the fee future.
+    /// However, private inputs and outputs, and records with a private owner, are not checked.
+    pub fn publicly_involves(&self, address: &Address<N>) -> bool {
+        self.transitions().any(|transition| transition.publicly_involves(address))
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -296,6 +408,7 @@ impl<N: Network> Transaction<N> {
     }
 
     /// Returns an iterator over all transitions.
+    /// The execution transitions (if any) are returned in order, followed by the fee transition (if any).
     pub fn transitions(&self) -> impl '_ + DoubleEndedIterator<Item = &Transition<N>> {
         match self {
             Self::Deploy(_, _, _, fee) => IterWrap::Deploy(Some(fee.transition()).into_iter()),
@@ -306,6 +419,43 @@ impl<N: Network> Transaction<N> {
         }
     }
 
+    /// Returns the number of distinct program IDs, for all transitions.
+    /// Note: This count includes the program of the fee transition (i.e. `credits.aleo`),
+    /// as well as the deployed program, if the transaction is a deployment.
+    pub fn distinct_program_count(&self) -> usize {
+        let deployed_program_id = self.deployment().map(|deployment| deployment.program_id());
+        self.transitions().map(Transition::program_id).chain(deployed_program_id).collect::<IndexSet<_>>().len()
+    }
+
+    /// Returns the shape hash of the transaction, for bucketing transactions by structure.
+    ///
+    /// The shape hash is the SHA3-256 hash of the variant (`0` for deploy, `1` for execute, `2` for fee),
+    /// followed by the byte encodings of the program ID and function name of each transition,
+    /// in the order of `Transaction::transitions` (i.e. including the fee transition).
+    /// No other fields contribute, so the shape hash is independent of the inputs, outputs, proofs,
+    /// and fee amounts, as well as the deployed program of a deployment.
+    pub fn shape_hash(&self) -> Result<[u8; 32]> {
+        // Determine the variant.
+        let variant: u8 = match self {
+            Self::Deploy(..) => 0,
+            Self::Execute(..) => 1,
+            Self::Fee(..) => 2,
+        };
+        // Construct the preimage.
+        // Note: The identifier encodings are length-prefixed, so the preimage is unambiguous.
+        let mut preimage = vec![variant];
+        for transition in self.transitions() {
+            transition.program_id().write_le(&mut preimage)?;
+            transition.function_name().write_le(&mut preimage)?;
+        }
+        // Hash the preimage.
+        let digest = Vec::<u8>::from_bits_le(&N::hash_sha3_256(&preimage.to_bits_le())?)?;
+        match <[u8; 32]>::try_from(digest) {
+            Ok(digest) => Ok(digest),
+            Err(digest) => bail!("Expected a 32-byte shape hash, found {} bytes", digest.len()),
+        }
+    }
+
     /* Input */
 
     /// Returns an iterator over the input IDs, for all transition inputs that are records.
@@ -318,6 +468,12 @@ impl<N: Network> Transaction<N> {
         self.transitions().flat_map(Transition::serial_numbers)
     }
 
+    /// Returns the number of serial numbers the transaction adds to the nullifier set, if accepted.
+    /// Note: This includes the serial numbers spent by the fee transition, as a private fee spends a record.
+    pub fn nullifier_set_growth(&self) -> usize {
+        self.serial_numbers().count()
+    }
+
     /// Returns an iterator over the tags, for all transition inputs that are records.
     pub fn tags(&self) -> impl '_ + Iterator<Item = &Field<N>> {
         self.transitions().flat_map(Transition::tags)
@@ -345,6 +501,12 @@ impl<N: Network> Transaction<N> {
         self.transitions().flat_map(Transition::nonces)
     }
 
+    /// Returns the number of public outputs, for all transitions.
+    /// Note: This count excludes records, as well as constant, private, external record, and future outputs.
+    pub fn public_output_count(&self) -> usize {
+        self.transitions().flat_map(Transition::outputs).filter(|output| matches!(output, Output::Public(..))).count()
+    }
+
     /// Returns an iterator over the transition public keys, for all transitions.
     pub fn transition_public_keys(&self) -> impl '_ + DoubleEndedIterator<Item = &Group<N>> {
         self.transitions().map(Transition::tpk)
@@ -363,6 +525,8 @@ impl<N: Network> Transaction<N> {
     }
 
     /// Returns a consuming iterator over all transitions.
+    /// The execution transitions (if any) are returned in order, followed by the fee transition (if any).
+    /// As such, a deploy or fee transaction yields only its fee transition.
     pub fn into_transitions(self) -> impl DoubleEndedIterator<Item = Transition<N>> {
         match self {
             Self::Deploy(_, _, _, fee) => IterWrap::Deploy(Some(fee.into_transition()).into_iter()),
@@ -453,6 +617,17 @@ pub mod test_helpers {
         Transaction::from_execution(execution, Some(fee)).unwrap()
     }
 
+    /// Returns a feeless execution transaction, whose execution consists of the given transition.
+    pub fn sample_execution_transaction_with_transition(
+        transition: Transition<CurrentNetwork>,
+        global_state_root: <CurrentNetwork as Network>::StateRoot,
+    ) -> Transaction<CurrentNetwork> {
+        // Construct the execution.
+        let execution = Execution::from([transition].into_iter(), global_state_root, None).unwrap();
+        // Construct an execution transaction.
+        Transaction::from_execution(execution, None).unwrap()
+    }
+
     /// Samples a random fee transaction.
     pub fn sample_private_fee_transaction(rng: &mut TestRng) -> Transaction<CurrentNetwork> {
         // Sample a fee.
@@ -469,3 +644,243 @@ pub mod test_helpers {
         Transaction::from_fee(fee).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::MainnetV0, program::ProgramOwner};
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Samples a deployment transaction for a program that imports `credits.aleo`,
+    /// returning the transaction and the private key of the program owner.
+    fn sample_deployment_transaction_with_import(
+        rng: &mut TestRng,
+    ) -> (Transaction<CurrentNetwork>, PrivateKey<CurrentNetwork>) {
+        // Initialize a new program, which imports `credits.aleo`.
+        let program = synthesizer_program::Program::<CurrentNetwork>::from_str(
+            r"
+import credits.aleo;
+
+program importer.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+        )
+        .unwrap();
+        // Compute the deployment.
+        let process = synthesizer_process::Process::load().unwrap();
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Note: This is a testing-only hack to adhere to Rust's dependency cycle rules.
+        let deployment = Deployment::from_str(&deployment.to_string()).unwrap();
+
+        // Construct the program owner, and the fee.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let deployment_id = deployment.to_deployment_id().unwrap();
+        let owner = ProgramOwner::new(&private_key, deployment_id, rng).unwrap();
+        let fee = crate::transaction::fee::test_helpers::sample_fee_public(deployment_id, rng);
+        // Construct the deployment transaction.
+        (Transaction::from_deployment(owner, deployment, fee).unwrap(), private_key)
+    }
+
+    #[test]
+    fn test_map_fee() {
+        let rng = &mut TestRng::default();
+
+        let deployment = crate::transaction::test_helpers::sample_deployment_transaction(true, rng);
+        let execution = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let fee = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+
+        // Ensure the fee of a deployment is replaced, and the ID is recomputed.
+        let deployment_id = deployment.deployment().unwrap().to_deployment_id().unwrap();
+        let new_fee = crate::transaction::fee::test_helpers::sample_fee_public(deployment_id, rng);
+        let candidate = deployment
+            .clone()
+            .map_fee(|fee| {
+                // Ensure the closure is given the existing fee.
+                assert_eq!(fee, deployment.fee_transition());
+                Ok(Some(new_fee.clone()))
+            })
+            .unwrap();
+        assert_eq!(candidate.fee_transition(), Some(new_fee.clone()));
+        assert_ne!(candidate.id(), deployment.id());
+        let (owner, body) = (*deployment.owner().unwrap(), deployment.deployment().unwrap().clone());
+        assert_eq!(candidate, Transaction::from_deployment(owner, body, new_fee).unwrap());
+
+        // Ensure the fee of an execution is removed, and the ID is recomputed.
+        let candidate = execution.clone().map_fee(|_| Ok(None)).unwrap();
+        assert_eq!(candidate.fee_transition(), None);
+        assert_eq!(candidate, Transaction::from_execution(execution.execution().unwrap().clone(), None).unwrap());
+        // Ensure the fee of an execution is restored.
+        let restored = candidate.map_fee(|_| Ok(execution.fee_transition())).unwrap();
+        assert_eq!(restored, execution);
+
+        // Ensure the fee of a fee transaction is replaced.
+        let new_fee = crate::transaction::test_helpers::sample_private_fee_transaction(rng).fee_transition();
+        let candidate = fee.clone().map_fee(|_| Ok(new_fee.clone())).unwrap();
+        assert_eq!(candidate.fee_transition(), new_fee);
+        assert!(candidate.is_fee());
+
+        // Ensure the fee may not be removed from a deployment or a fee transaction.
+        assert!(deployment.clone().map_fee(|_| Ok(None)).is_err());
+        assert!(fee.clone().map_fee(|_| Ok(None)).is_err());
+        // Ensure an error from the closure is propagated.
+        assert!(execution.map_fee(|_| bail!("Failed to map the fee")).is_err());
+    }
+
+    #[test]
+    fn test_dependencies() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the imports of the deployed program are returned.
+        let (transaction, _) = sample_deployment_transaction_with_import(rng);
+        assert_eq!(transaction.dependencies(), vec![ProgramID::from_str("credits.aleo").unwrap()]);
+
+        // Ensure a deployment without imports, an execution, and a fee have no dependencies.
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            assert!(transaction.dependencies().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_deployment_owner_address() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the address of the program owner is returned.
+        let (transaction, private_key) = sample_deployment_transaction_with_import(rng);
+        let expected = Address::try_from(&private_key).unwrap();
+        assert_eq!(transaction.deployment_owner_address(), Some(expected));
+
+        // Ensure an execution and a fee have no program owner.
+        for transaction in [
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            assert_eq!(transaction.deployment_owner_address(), None);
+        }
+    }
+
+    #[test]
+    fn test_public_output_count() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_private_fee_transaction(rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            // Count the public outputs, from their JSON serialization.
+            let expected = transaction
+                .transitions()
+                .flat_map(Transition::outputs)
+                .filter(|output| serde_json::to_value(output).unwrap()["type"] == "public")
+                .count();
+            assert_eq!(transaction.public_output_count(), expected);
+        }
+
+        // Ensure a fee transition has no public outputs, as it outputs a future (and a record, if private).
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        assert_eq!(transaction.public_output_count(), 0);
+    }
+
+    #[test]
+    fn test_distinct_program_count() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a deployment counts the deployed program, and `credits.aleo` for the fee.
+        let transaction = crate::transaction::test_helpers::sample_deployment_transaction(true, rng);
+        assert_eq!(transaction.distinct_program_count(), 2);
+        // Ensure a fee transaction counts only `credits.aleo`.
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        assert_eq!(transaction.distinct_program_count(), 1);
+
+        // Ensure an execution counts each program once, including the fee.
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let expected = transaction.transitions().map(|transition| transition.program_id().to_string());
+        assert_eq!(transaction.distinct_program_count(), expected.collect::<std::collections::HashSet<_>>().len());
+    }
+
+    #[test]
+    fn test_fee_paying_transition() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            // Ensure the fee paying transition is the fee transition.
+            let fee = transaction.fee_transition().unwrap();
+            assert_eq!(transaction.fee_paying_transition(), Some(fee.transition()));
+            assert!(fee.is_fee_private() || fee.is_fee_public());
+        }
+
+        // Ensure an execution without a fee has no fee paying transition.
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction = Transaction::from_execution(transaction.execution().unwrap().clone(), None).unwrap();
+        assert_eq!(transaction.fee_paying_transition(), None);
+    }
+
+    #[test]
+    fn test_shape_hash() {
+        let rng = &mut TestRng::default();
+
+        // Sample an execution, and an execution of the same shape with a different fee.
+        let execution = crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let execution_id = execution.execution().unwrap().to_execution_id().unwrap();
+        let new_fee = crate::transaction::fee::test_helpers::sample_fee_public(execution_id, rng);
+        let other = execution.clone().map_fee(|_| Ok(Some(new_fee))).unwrap();
+        assert_ne!(execution.id(), other.id());
+        // Ensure the shape hash is stable across transactions of the same shape.
+        assert_eq!(execution.shape_hash().unwrap(), execution.shape_hash().unwrap());
+        assert_eq!(execution.shape_hash().unwrap(), other.shape_hash().unwrap());
+
+        // Ensure the shape hash differs when the variant, the fee function, or the number of transitions differ.
+        let transactions = [
+            execution.clone(),
+            execution.map_fee(|_| Ok(None)).unwrap(),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ];
+        let shape_hashes = transactions.iter().map(|transaction| transaction.shape_hash().unwrap());
+        assert_eq!(shape_hashes.collect::<std::collections::HashSet<_>>().len(), transactions.len());
+    }
+
+    #[test]
+    fn test_nullifier_set_growth() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_private_fee_transaction(rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            // Ensure the growth is the number of serial numbers, across all transitions.
+            let expected: usize = transaction.transitions().map(|transition| transition.serial_numbers().count()).sum();
+            assert_eq!(transaction.nullifier_set_growth(), expected);
+            assert_eq!(transaction.nullifier_set_growth(), transaction.serial_numbers().count());
+        }
+
+        // Ensure a private fee spends one record, and a public fee spends none.
+        let private_fee = crate::transaction::test_helpers::sample_private_fee_transaction(rng);
+        assert_eq!(private_fee.nullifier_set_growth(), 1);
+        let public_fee = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        assert_eq!(public_fee.nullifier_set_growth(), 0);
+
+        // Ensure the growth of an execution includes the record spent by its private fee.
+        let execution = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let without_fee = execution.clone().map_fee(|_| Ok(None)).unwrap();
+        assert_eq!(execution.nullifier_set_growth(), without_fee.nullifier_set_growth() + 1);
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Input;
+
+use console::{
+    program::{compute_function_id, Argument, Future, Identifier, Literal, Plaintext},
+    types::U16,
+};
+
+impl<N: Network> Transaction<N> {
+    /// Returns a placeholder fee transaction, whose contents are fully determined by the given RNG.
+    ///
+    /// The placeholder is a `credits.aleo/fee_public` transition for a base fee of 1 microcredit,
+    /// with well-formed input and output hashes, a sampled global state root, and *no* proof.
+    /// As such, it round-trips through the byte codec (which recomputes the transaction ID),
+    /// but its fee proof does *not* verify.
+    pub fn placeholder<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        Self::sample_placeholder(rng).expect("Failed to construct a placeholder transaction")
+    }
+
+    /// Returns a placeholder fee transaction, whose contents are fully determined by the given RNG.
+    fn sample_placeholder<R: Rng + CryptoRng>(rng: &mut R) -> Result<Self> {
+        // Construct the locator for the fee function.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let function_name = Identifier::from_str("fee_public")?;
+        // Compute the function ID.
+        let function_id = compute_function_id(&U16::new(N::ID), &program_id, &function_name)?;
+
+        // Sample the transition public key, transition commitment, and signer commitment.
+        let tpk = Group::rand(rng);
+        let tcm = Field::rand(rng);
+        let scm = Field::rand(rng);
+
+        // Computes the hash of a public input or future output, as `Hash(function ID || value || tcm || index)`.
+        let hash = |fields: Vec<Field<N>>, index: u16| {
+            let mut preimage = Vec::with_capacity(fields.len() + 3);
+            preimage.push(function_id);
+            preimage.extend(fields);
+            preimage.push(tcm);
+            preimage.push(Field::from_u16(index));
+            N::hash_psd8(&preimage)
+        };
+
+        // Construct the inputs as `(base_fee, priority_fee, deployment_or_execution_id)`.
+        let inputs = [
+            Plaintext::from(Literal::U64(U64::new(1))),
+            Plaintext::from(Literal::U64(U64::new(0))),
+            Plaintext::from(Literal::Field(Field::rand(rng))),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(index, input)| Ok(Input::Public(hash(input.to_fields()?, index as u16)?, Some(input))))
+        .collect::<Result<Vec<_>>>()?;
+
+        // Construct the future output as `(payer, amount)`.
+        let future = Future::new(program_id, function_name, vec![
+            Argument::Plaintext(Plaintext::from(Literal::Address(Address::rand(rng)))),
+            Argument::Plaintext(Plaintext::from(Literal::U64(U64::new(1)))),
+        ]);
+        let outputs = vec![Output::Future(hash(future.to_fields()?, inputs.len() as u16)?, Some(future))];
+
+        // Construct the fee.
+        let transition = Transition::new(program_id, function_name, inputs, outputs, tpk, tcm, scm)?;
+        let fee = Fee::from(transition, Uniform::rand(rng), None)?;
+        // Construct the fee transaction.
+        Self::from_fee(fee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_placeholder() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            let seed: u64 = rng.gen();
+
+            // Ensure the placeholder is deterministic for a given seed.
+            let expected = Transaction::<CurrentNetwork>::placeholder(&mut TestRng::fixed(seed));
+            let candidate = Transaction::<CurrentNetwork>::placeholder(&mut TestRng::fixed(seed));
+            assert_eq!(expected, candidate);
+            assert!(expected.is_fee());
+
+            // Ensure the placeholder round-trips through the byte codec.
+            let bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, Transaction::read_le(&bytes[..]).unwrap());
+
+            // Ensure the inputs and outputs are well-formed.
+            let fee = expected.fee_transition().unwrap();
+            let transition = fee.transition();
+            let function_id =
+                compute_function_id(&U16::new(CurrentNetwork::ID), transition.program_id(), transition.function_name())
+                    .unwrap();
+            for (index, input) in transition.inputs().iter().enumerate() {
+                assert!(input.verify(function_id, transition.tcm(), index));
+            }
+            for (index, output) in transition.outputs().iter().enumerate() {
+                assert!(output.verify(function_id, transition.tcm(), transition.inputs().len() + index));
+            }
+            assert_eq!(*fee.amount().unwrap(), 1);
+            assert!(fee.payer().is_some());
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::fmt::Write as _;
+
+impl<N: Network> Transaction<N> {
+    /// The version of the canonical pretty-print format.
+    pub const CANONICAL_PRETTY_VERSION: u8 = 1;
+
+    /// Returns a human-readable, multiline representation of the transaction, in a stable format.
+    ///
+    /// The first line names the format version. Each following line is a `key: value` pair, in a fixed order,
+    /// and the deployment and proofs are printed as the hex-encoded SHA3-256 hash of their bytes.
+    /// Note: Unlike `Debug` and `Display`, this format is **not** changed without bumping its version.
+    ///
+    /// Returns an error if the deployment or a proof fails to serialize, or if the fee amount is malformed.
+    pub fn to_canonical_pretty(&self) -> Result<String> {
+        // Returns the hex-encoded SHA3-256 hash of the given bytes.
+        let digest = |bytes: Vec<u8>| -> Result<String> {
+            Ok(format!("sha3-256:{}", hex::encode(Vec::<u8>::from_bits_le(&N::hash_sha3_256(&bytes.to_bits_le())?)?)))
+        };
+        // Returns the hash of the given proof, or `none`.
+        let proof = |proof: Option<&synthesizer_snark::Proof<N>>| match proof {
+            Some(proof) => digest(proof.to_bytes_le()?),
+            None => Ok("none".to_string()),
+        };
+
+        let mut output = format!("transaction.canonical.v{}\n", Self::CANONICAL_PRETTY_VERSION);
+        writeln!(output, "id: {}", self.id())?;
+        match self {
+            Self::Deploy(_, owner, deployment, _) => {
+                writeln!(output, "type: deploy")?;
+                writeln!(output, "owner: {}", owner.address())?;
+                writeln!(output, "program: {}", deployment.program_id())?;
+                writeln!(output, "edition: {}", deployment.edition())?;
+                writeln!(output, "deployment: {}", digest(deployment.to_bytes_le()?)?)?;
+            }
+            Self::Execute(_, execution, _) => {
+                writeln!(output, "type: execute")?;
+                writeln!(output, "global_state_root: {}", execution.global_state_root())?;
+                writeln!(output, "proof: {}", proof(execution.proof())?)?;
+                for (index, transition) in execution.transitions().enumerate() {
+                    writeln!(output, "transition[{index}]: {}", pretty_transition(transition))?;
+                }
+            }
+            Self::Fee(..) => writeln!(output, "type: fee")?,
+        }
+        match self.fee_transition() {
+            Some(fee) => {
+                writeln!(output, "fee.transition: {}", pretty_transition(fee.transition()))?;
+                writeln!(output, "fee.global_state_root: {}", fee.global_state_root())?;
+                writeln!(output, "fee.proof: {}", proof(fee.proof())?)?;
+                writeln!(output, "fee.amount: {}", *fee.amount()?)?;
+            }
+            None => writeln!(output, "fee: none")?,
+        }
+        Ok(output)
+    }
+}
+
+/// Returns the transition as `{id} {program_id}/{function_name} inputs={count} outputs={count}`.
+fn pretty_transition<N: Network>(transition: &Transition<N>) -> String {
+    format!(
+        "{} {}/{} inputs={} outputs={}",
+        transition.id(),
+        transition.program_id(),
+        transition.function_name(),
+        transition.inputs().len(),
+        transition.outputs().len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_canonical_pretty() {
+        // Pin the format of a placeholder fee transaction, sampled from a fixed seed.
+        let transaction = Transaction::<CurrentNetwork>::placeholder(&mut TestRng::fixed(1));
+        let expected = "transaction.canonical.v1
+id: at19a500h058x2re8df97ynf6ps7q2v96krr2gvd90hcn6rn87zgygsr88lve
+type: fee
+fee.transition: au1p2ccjj9qgyh7quspaunhpg3gqecwxf5dklddmt95dquka8w9xcpsfkxyzr credits.aleo/fee_public inputs=3 outputs=1
+fee.global_state_root: sr1hjsr8dvs0jf75uyv66yva20qv49av5679knq0hc70hsqghjyrvgs0v87np
+fee.proof: none
+fee.amount: 1
+";
+        assert_eq!(expected, transaction.to_canonical_pretty().unwrap());
+
+        // Ensure the output is deterministic, and the blobs are hashed.
+        let rng = &mut TestRng::default();
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+        ] {
+            let pretty = transaction.to_canonical_pretty().unwrap();
+            assert_eq!(pretty, transaction.clone().to_canonical_pretty().unwrap());
+            assert!(pretty.starts_with("transaction.canonical.v1\n"));
+            assert!(pretty.lines().filter(|line| line.contains("sha3-256:")).count() >= 2);
+            assert!(pretty.lines().all(|line| line.len() < 200));
+        }
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use synthesizer_snark::Proof;
+
+/// A preview of an encoded transaction, which holds its header and up to a maximum number of its transitions.
+///
+/// The transitions are in the order of `Transaction::transitions`, with the fee transition (if any) last.
+/// Note: The transaction ID is *not* checked, as this requires decoding the full transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionPreview<N: Network> {
+    /// The transaction ID.
+    id: N::TransactionID,
+    /// The transaction variant.
+    variant: u8,
+    /// The decoded transitions.
+    transitions: Vec<Transition<N>>,
+}
+
+impl<N: Network> TransactionPreview<N> {
+    /// Returns the transaction ID.
+    pub const fn id(&self) -> &N::TransactionID {
+        &self.id
+    }
+
+    /// Returns the transaction variant, as `0` for deploy, `1` for execute, and `2` for fee.
+    pub const fn variant(&self) -> u8 {
+        self.variant
+    }
+
+    /// Returns the decoded transitions.
+    pub fn transitions(&self) -> &[Transition<N>] {
+        &self.transitions
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Reads the header of the transaction and up to `max_transitions` of its transitions from the buffer,
+    /// returning the preview, and `true` if the transaction has more transitions than were decoded.
+    ///
+    /// Decoding stops after the last decoded transition, so the reader may not be consumed in full.
+    /// For a deploy transaction, no transitions are decoded, as the fee transition follows the deployment.
+    /// Note: At most `N::MAX_TRANSACTION_SIZE` bytes are consumed from the reader.
+    pub fn read_le_preview<R: Read>(reader: R, max_transitions: usize) -> IoResult<(TransactionPreview<N>, bool)> {
+        // Limit the number of bytes consumed to the maximum transaction size.
+        let mut reader = reader.take(N::MAX_TRANSACTION_SIZE as u64);
+
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid transaction version"));
+        }
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Ensure the variant is valid.
+        if variant > 2 {
+            return Err(error("Invalid transaction variant"));
+        }
+        // Read the ID.
+        let id = N::TransactionID::read_le(&mut reader)?;
+
+        // Read the transitions.
+        let mut transitions = Vec::new();
+        let truncated = match variant {
+            0 => true,
+            1 => {
+                // Read the execution version.
+                let version = u8::read_le(&mut reader)?;
+                // Ensure the execution version is valid.
+                if version != 1 {
+                    return Err(error("Invalid execution version"));
+                }
+                // Read the number of transitions.
+                let num_transitions = u8::read_le(&mut reader)? as usize;
+                // Ensure the number of transitions is nonzero.
+                if num_transitions == 0 {
+                    return Err(error("Execution (from 'preview') has no transitions"));
+                }
+                // Read the transitions, up to the maximum.
+                for _ in 0..num_transitions.min(max_transitions) {
+                    transitions.push(Transition::read_le(&mut reader)?);
+                }
+                match num_transitions > max_transitions {
+                    true => true,
+                    // If all of the transitions were read, read on to the fee transition.
+                    false => {
+                        // Read the global state root.
+                        N::StateRoot::read_le(&mut reader)?;
+                        // Read the proof.
+                        match u8::read_le(&mut reader)? {
+                            0 => (),
+                            1 => drop(Proof::<N>::read_le(&mut reader)?),
+                            proof_variant => return Err(error(format!("Invalid proof variant '{proof_variant}'"))),
+                        }
+                        // Read the fee variant.
+                        match u8::read_le(&mut reader)? {
+                            0 => false,
+                            1 if transitions.len() < max_transitions => {
+                                transitions.push(Fee::read_le(&mut reader)?.transition().clone());
+                                false
+                            }
+                            1 => true,
+                            _ => return Err(error("Invalid fee variant")),
+                        }
+                    }
+                }
+            }
+            _ => match max_transitions > 0 {
+                true => {
+                    transitions.push(Fee::read_le(&mut reader)?.transition().clone());
+                    false
+                }
+                false => true,
+            },
+        };
+
+        Ok((TransactionPreview { id, variant, transitions }, truncated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_read_le_preview() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let bytes = transaction.to_bytes_le().unwrap();
+            let expected = transaction.transitions().cloned().collect::<Vec<_>>();
+
+            for max_transitions in 0..=expected.len() + 1 {
+                let (preview, truncated) =
+                    Transaction::<CurrentNetwork>::read_le_preview(&bytes[..], max_transitions).unwrap();
+                assert_eq!(preview.id(), &transaction.id());
+                match &transaction {
+                    Transaction::Deploy(..) => {
+                        assert!(preview.transitions().is_empty());
+                        assert!(truncated);
+                    }
+                    _ => {
+                        let num_decoded = max_transitions.min(expected.len());
+                        assert_eq!(preview.transitions(), &expected[..num_decoded]);
+                        assert_eq!(truncated, num_decoded < expected.len());
+                    }
+                }
+            }
+        }
+
+        // Ensure a truncated encoding is rejected.
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        let bytes = transaction.to_bytes_le().unwrap();
+        assert!(Transaction::<CurrentNetwork>::read_le_preview(&bytes[..bytes.len() - 1], 1).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use synthesizer_snark::Proof;
+
+impl<N: Network> Transaction<N> {
+    /// The header byte of a transaction serialized without its proofs.
+    /// Note: This differs from the transaction version, so the full decoder rejects such bytes.
+    pub const PROOFLESS_HEADER: u8 = 0xFE;
+
+    /// Returns a copy of the transaction, with each proof and each deployment certificate replaced
+    /// by a zeroed placeholder of the same size.
+    ///
+    /// As the transaction ID does not commit to the proofs or certificates, the redacted transaction preserves
+    /// the ID, the structure, and the serialized size of the transaction. However, it does *not* verify.
+    pub fn redact_proofs(&self) -> Result<Self> {
+        // Returns the given fee, with its proof redacted.
+        let redact_fee = |fee: &Fee<N>| -> Result<Fee<N>> {
+            let proof = fee.proof().map(Proof::redacted).transpose()?;
+            Ok(Fee::from_unchecked(fee.transition().clone(), fee.global_state_root(), proof))
+        };
+
+        match self {
+            Self::Deploy(id, owner, deployment, fee) => {
+                // Redact the certificates.
+                let verifying_keys = deployment
+                    .verifying_keys()
+                    .iter()
+                    .map(|(function_name, (verifying_key, certificate))| {
+                        Ok((*function_name, (verifying_key.clone(), certificate.redacted()?)))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let deployment = Deployment::new(deployment.edition(), deployment.program().clone(), verifying_keys)?;
+                Ok(Self::Deploy(*id, *owner, Box::new(deployment), redact_fee(fee)?))
+            }
+            Self::Execute(id, execution, fee) => {
+                // Redact the execution proof.
+                let execution = Execution::from(
+                    execution.transitions().cloned(),
+                    execution.global_state_root(),
+                    execution.proof().map(Proof::redacted).transpose()?,
+                )?;
+                Ok(Self::Execute(*id, execution, fee.as_ref().map(redact_fee).transpose()?))
+            }
+            Self::Fee(id, fee) => Ok(Self::Fee(*id, redact_fee(fee)?)),
+        }
+    }
+
+    /// Returns the bytes of the transaction with its proofs omitted, prefixed with `Transaction::PROOFLESS_HEADER`.
+    ///
+    /// As the transaction ID does not commit to the proofs, the decoded transaction preserves the ID.
+    /// However, it has no proofs, so it does *not* verify.
+    /// Note: Unlike `Transaction::redact_proofs`, the deployment certificates are retained in full,
+    /// as the encoding of a `Deployment` requires a certificate for each verifying key.
+    pub fn bytes_excluding_proofs(&self) -> Result<Vec<u8>> {
+        // Write the header, followed by the transaction without its proofs.
+        let mut bytes = vec![Self::PROOFLESS_HEADER];
+        self.without_proofs()?.write_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns a copy of the transaction, with its proofs removed, and its deployment certificates retained.
+    /// As the transaction ID does not commit to the proofs, the copy preserves the ID.
+    pub(crate) fn without_proofs(&self) -> Result<Self> {
+        // Returns the given fee, without its proof.
+        let strip_fee = |fee: &Fee<N>| Fee::from_unchecked(fee.transition().clone(), fee.global_state_root(), None);
+
+        match self {
+            Self::Deploy(id, owner, deployment, fee) => {
+                Ok(Self::Deploy(*id, *owner, deployment.clone(), strip_fee(fee)))
+            }
+            Self::Execute(id, execution, fee) => {
+                let execution = Execution::from(execution.transitions().cloned(), execution.global_state_root(), None)?;
+                Ok(Self::Execute(*id, execution, fee.as_ref().map(strip_fee)))
+            }
+            Self::Fee(id, fee) => Ok(Self::Fee(*id, strip_fee(fee))),
+        }
+    }
+
+    /// Reads a transaction from bytes produced by `Transaction::bytes_excluding_proofs`.
+    /// The proofs in the returned transaction are `None`.
+    pub fn from_bytes_excluding_proofs(bytes: &[u8]) -> Result<Self> {
+        // Read the header.
+        let Some((&header, mut reader)) = bytes.split_first() else {
+            bail!("Missing the proofless transaction header")
+        };
+        // Ensure the header is valid.
+        ensure!(header == Self::PROOFLESS_HEADER, "Invalid proofless transaction header '{header}'");
+        // Read the transaction.
+        let transaction = Self::read_le(&mut reader)?;
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the transaction", reader.len());
+        // Ensure the transaction does not contain proofs.
+        let has_execution_proof = transaction.execution().is_some_and(|execution| execution.proof().is_some());
+        let has_fee_proof = transaction.fee_transition().is_some_and(|fee| fee.proof().is_some());
+        ensure!(!has_execution_proof && !has_fee_proof, "Found a proof in a proofless transaction");
+        Ok(transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synthesizer_snark::Certificate;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_redact_proofs() {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_private_fee_transaction(rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let redacted = expected.redact_proofs().unwrap();
+            // Ensure the ID and transitions are preserved.
+            assert_eq!(expected.id(), redacted.id());
+            assert!(expected.transitions().eq(redacted.transitions()));
+            // Ensure the proofs are redacted.
+            let proofs = |transaction: &Transaction<CurrentNetwork>| {
+                let mut proofs = Vec::new();
+                proofs.extend(transaction.execution().and_then(Execution::proof).cloned());
+                proofs.extend(transaction.fee_transition().and_then(|fee| fee.proof().cloned()));
+                proofs
+            };
+            let (expected_proofs, redacted_proofs) = (proofs(&expected), proofs(&redacted));
+            assert_eq!(expected_proofs.len(), redacted_proofs.len());
+            for (expected_proof, redacted_proof) in expected_proofs.iter().zip(&redacted_proofs) {
+                assert_ne!(expected_proof, redacted_proof);
+                assert_eq!(&expected_proof.redacted().unwrap(), redacted_proof);
+            }
+            // Ensure the certificates are redacted.
+            let certificates = |transaction: &Transaction<CurrentNetwork>| {
+                let verifying_keys = transaction.deployment().map(Deployment::verifying_keys).into_iter().flatten();
+                verifying_keys.map(|(_, (_, certificate))| certificate.clone()).collect::<Vec<Certificate<_>>>()
+            };
+            let (expected_certificates, redacted_certificates) = (certificates(&expected), certificates(&redacted));
+            assert_eq!(expected_certificates.len(), redacted_certificates.len());
+            for (expected_certificate, redacted_certificate) in expected_certificates.iter().zip(&redacted_certificates)
+            {
+                assert_ne!(expected_certificate, redacted_certificate);
+                assert_eq!(&expected_certificate.redacted().unwrap(), redacted_certificate);
+            }
+
+            // Ensure the redacted transaction has the same size, and round-trips through the byte codec.
+            let bytes = redacted.to_bytes_le().unwrap();
+            assert_eq!(expected.to_bytes_le().unwrap().len(), bytes.len());
+            assert_eq!(redacted, Transaction::read_le(&bytes[..]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bytes_excluding_proofs() {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let bytes = expected.bytes_excluding_proofs().unwrap();
+            // Ensure the bytes are smaller than the full format, and are rejected by the full decoder.
+            assert!(bytes.len() < expected.to_bytes_le().unwrap().len());
+            assert!(Transaction::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+            // Ensure the decoded transaction preserves the ID and transitions, without proofs.
+            let candidate = Transaction::<CurrentNetwork>::from_bytes_excluding_proofs(&bytes).unwrap();
+            assert_eq!(expected.id(), candidate.id());
+            assert!(expected.transitions().eq(candidate.transitions()));
+            assert!(candidate.execution().and_then(Execution::proof).is_none());
+            assert!(candidate.fee_transition().unwrap().proof().is_none());
+            // Ensure the deployment certificates are retained, unlike in `Transaction::redact_proofs`.
+            assert_eq!(expected.deployment(), candidate.deployment());
+
+            // Ensure the full format is rejected by the proofless decoder.
+            let full_bytes = expected.to_bytes_le().unwrap();
+            assert!(Transaction::<CurrentNetwork>::from_bytes_excluding_proofs(&full_bytes).is_err());
+            let mut full_bytes = full_bytes;
+            full_bytes.insert(0, Transaction::<CurrentNetwork>::PROOFLESS_HEADER);
+            assert!(Transaction::<CurrentNetwork>::from_bytes_excluding_proofs(&full_bytes).is_err());
+        }
+    }
+}
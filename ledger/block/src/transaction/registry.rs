@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::Identifier;
+
+/// A source of deployed programs, against which the calls in a transaction are validated.
+pub trait ProgramRegistry<N: Network> {
+    /// Returns `true` if the given program is deployed.
+    fn contains_program(&self, program_id: &ProgramID<N>) -> bool;
+
+    /// Returns `true` if the given program is deployed, and contains the given function.
+    fn contains_function(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> bool;
+}
+
+impl<N: Network> Transaction<N> {
+    /// Ensures every program and function called in the transaction exists in the given registry.
+    ///
+    /// Note: This checks the transitions of the transaction, including the fee transition.
+    /// The program published by a deployment is not required to exist in the registry.
+    pub fn validate_against<R: ProgramRegistry<N>>(&self, registry: &R) -> Result<()> {
+        for transition in self.transitions() {
+            let (program_id, function_name) = (transition.program_id(), transition.function_name());
+            // Ensure the program exists.
+            ensure!(
+                registry.contains_program(program_id),
+                "Transaction '{}' calls program '{program_id}', which does not exist in the registry",
+                self.id()
+            );
+            // Ensure the function exists.
+            ensure!(
+                registry.contains_function(program_id, function_name),
+                "Transaction '{}' calls function '{program_id}/{function_name}', which does not exist in the registry",
+                self.id()
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indexmap::IndexMap;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// A registry of program IDs to their function names.
+    struct SampleRegistry(IndexMap<ProgramID<CurrentNetwork>, IndexSet<Identifier<CurrentNetwork>>>);
+
+    impl ProgramRegistry<CurrentNetwork> for SampleRegistry {
+        fn contains_program(&self, program_id: &ProgramID<CurrentNetwork>) -> bool {
+            self.0.contains_key(program_id)
+        }
+
+        fn contains_function(
+            &self,
+            program_id: &ProgramID<CurrentNetwork>,
+            function_name: &Identifier<CurrentNetwork>,
+        ) -> bool {
+            self.0.get(program_id).is_some_and(|functions| functions.contains(function_name))
+        }
+    }
+
+    #[test]
+    fn test_validate_against() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            // Construct a registry with every called program and function.
+            let mut registry = SampleRegistry(IndexMap::new());
+            for transition in transaction.transitions() {
+                registry.0.entry(*transition.program_id()).or_default().insert(*transition.function_name());
+            }
+            assert!(transaction.validate_against(&registry).is_ok());
+
+            // Ensure a missing function is rejected.
+            let transition = transaction.transitions().next().unwrap();
+            let mut missing_function = SampleRegistry(registry.0.clone());
+            missing_function.0.get_mut(transition.program_id()).unwrap().swap_remove(transition.function_name());
+            let error = transaction.validate_against(&missing_function).unwrap_err().to_string();
+            assert!(error.contains(&format!("{}/{}", transition.program_id(), transition.function_name())));
+
+            // Ensure a missing program is rejected.
+            let mut missing_program = SampleRegistry(registry.0.clone());
+            missing_program.0.swap_remove(transition.program_id());
+            let error = transaction.validate_against(&missing_program).unwrap_err().to_string();
+            assert!(error.contains(&format!("program '{}'", transition.program_id())));
+        }
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Transaction<N> {
+    /// Returns `true` if the given transaction is identical to this one, except for its fee.
+    ///
+    /// For deploy transactions, the program owner and the deployment must be equal, and the fees must differ.
+    /// For execute transactions, the executions must be equal, and the fees (if any) must differ.
+    /// Fee transactions, and transactions of different variants, never differ only in their fee.
+    ///
+    /// Note: The comparison is exact, and *includes* the proofs. As proving is randomized, two executions
+    /// of the same call that were proven separately are *not* equal, so this only recognizes a replacement
+    /// that reuses the deployment or execution (with its proof) of the original transaction.
+    pub fn differs_only_in_fee(&self, other: &Transaction<N>) -> bool {
+        match (self, other) {
+            (Self::Deploy(_, owner, deployment, fee), Self::Deploy(_, other_owner, other_deployment, other_fee)) => {
+                owner == other_owner && deployment == other_deployment && fee != other_fee
+            }
+            (Self::Execute(_, execution, fee), Self::Execute(_, other_execution, other_fee)) => {
+                execution == other_execution && fee != other_fee
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_differs_only_in_fee() {
+        let rng = &mut TestRng::default();
+
+        let deployment = crate::transaction::test_helpers::sample_deployment_transaction(true, rng);
+        let execution = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let other_execution = crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let fee = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        let other_fee = fee.fee_transition().unwrap();
+
+        // Ensure a transaction does not differ only in fee from itself.
+        for transaction in [&deployment, &execution, &fee] {
+            assert!(!transaction.differs_only_in_fee(transaction));
+        }
+
+        // Ensure a deployment with a different fee is recognized.
+        let (owner, deployment_body) = (*deployment.owner().unwrap(), deployment.deployment().unwrap().clone());
+        let replacement = Transaction::from_deployment(owner, deployment_body, other_fee.clone()).unwrap();
+        assert!(deployment.differs_only_in_fee(&replacement));
+        assert!(replacement.differs_only_in_fee(&deployment));
+
+        // Ensure an execution with a different (or no) fee is recognized.
+        let execution_body = execution.execution().unwrap().clone();
+        for fee in [None, Some(other_fee)] {
+            let replacement = Transaction::from_execution(execution_body.clone(), fee).unwrap();
+            assert!(execution.differs_only_in_fee(&replacement));
+            assert!(replacement.differs_only_in_fee(&execution));
+        }
+
+        // Ensure different executions, and different variants, are not recognized.
+        assert!(!execution.differs_only_in_fee(&other_execution));
+        assert!(!execution.differs_only_in_fee(&deployment));
+        assert!(!fee.differs_only_in_fee(&execution));
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::HashSet;
+
+impl<N: Network> Transaction<N> {
+    /// Returns an iterator over the global state roots referenced by the transaction.
+    /// Note: An execute transaction references the state root of its execution, and of its fee (if any).
+    pub fn global_state_roots(&self) -> impl '_ + Iterator<Item = N::StateRoot> {
+        let (execution, fee) = match self {
+            Self::Deploy(_, _, _, fee) => (None, Some(fee)),
+            Self::Execute(_, execution, fee) => (Some(execution), fee.as_ref()),
+            Self::Fee(_, fee) => (None, Some(fee)),
+        };
+        execution.map(Execution::global_state_root).into_iter().chain(fee.map(Fee::global_state_root))
+    }
+
+    /// Ensures every global state root referenced by the given transactions is in the allowed set.
+    /// Returns an error naming the first transaction and state root that is not allowed.
+    pub fn verify_batch_roots(transactions: &[Self], allowed_roots: &HashSet<Field<N>>) -> Result<()> {
+        for transaction in transactions {
+            for root in transaction.global_state_roots() {
+                ensure!(
+                    allowed_roots.contains(&*root),
+                    "Transaction '{}' references a global state root '{root}' that is not allowed",
+                    transaction.id()
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_verify_batch_roots() {
+        let rng = &mut TestRng::default();
+
+        let transactions = [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ];
+
+        // Ensure the transactions are accepted when every referenced root is allowed.
+        let allowed_roots: HashSet<_> =
+            transactions.iter().flat_map(Transaction::global_state_roots).map(|root| *root).collect();
+        assert!(Transaction::verify_batch_roots(&transactions, &allowed_roots).is_ok());
+        assert!(Transaction::<CurrentNetwork>::verify_batch_roots(&[], &HashSet::new()).is_ok());
+
+        // Ensure a transaction is rejected when one of its roots is not allowed.
+        for transaction in &transactions {
+            for root in transaction.global_state_roots() {
+                let mut allowed_roots = allowed_roots.clone();
+                allowed_roots.remove(&*root);
+                let error = Transaction::verify_batch_roots(&transactions, &allowed_roots).unwrap_err().to_string();
+                assert!(error.contains(&root.to_string()));
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of bytes each top-level field occupies in an encoded transaction.
+///
+/// The header accounts for the version, the variant, and (for an execute transaction) the fee variant.
+/// Fields that are absent in the transaction variant have a size of zero.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SizeBreakdown {
+    /// The number of bytes of the header.
+    header: usize,
+    /// The number of bytes of the transaction ID.
+    id: usize,
+    /// The number of bytes of the program owner.
+    owner: usize,
+    /// The number of bytes of the deployment.
+    deployment: usize,
+    /// The number of bytes of the execution.
+    execution: usize,
+    /// The number of bytes of the fee.
+    fee: usize,
+}
+
+impl SizeBreakdown {
+    /// Returns the number of bytes of the header.
+    pub const fn header(&self) -> usize {
+        self.header
+    }
+
+    /// Returns the number of bytes of the transaction ID.
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the number of bytes of the program owner.
+    pub const fn owner(&self) -> usize {
+        self.owner
+    }
+
+    /// Returns the number of bytes of the deployment.
+    pub const fn deployment(&self) -> usize {
+        self.deployment
+    }
+
+    /// Returns the number of bytes of the execution.
+    pub const fn execution(&self) -> usize {
+        self.execution
+    }
+
+    /// Returns the number of bytes of the fee.
+    pub const fn fee(&self) -> usize {
+        self.fee
+    }
+
+    /// Returns the total number of bytes of the transaction.
+    pub const fn total(&self) -> usize {
+        self.header + self.id + self.owner + self.deployment + self.execution + self.fee
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the number of bytes each top-level field occupies in the encoded transaction,
+    /// where the sizes sum to the size of `Transaction::to_bytes_le`.
+    /// Note: Each field is encoded once to measure it, and nothing is decoded.
+    pub fn size_breakdown(&self) -> Result<SizeBreakdown> {
+        // Returns the number of bytes in the encoding of the given field.
+        fn size(field: &impl ToBytes) -> Result<usize> {
+            Ok(field.to_bytes_le()?.len())
+        }
+
+        // Account for the version and the variant.
+        let mut breakdown = SizeBreakdown { header: 2, id: size(&self.id())?, ..Default::default() };
+        // Compute the size of each field.
+        match self {
+            Self::Deploy(_, owner, deployment, fee) => {
+                breakdown.owner = size(owner)?;
+                breakdown.deployment = size(&**deployment)?;
+                breakdown.fee = size(fee)?;
+            }
+            Self::Execute(_, execution, fee) => {
+                // Account for the fee variant.
+                breakdown.header += 1;
+                breakdown.execution = size(execution)?;
+                breakdown.fee = fee.as_ref().map_or(Ok(0), size)?;
+            }
+            Self::Fee(_, fee) => breakdown.fee = size(fee)?,
+        }
+        Ok(breakdown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_breakdown() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let breakdown = transaction.size_breakdown().unwrap();
+            // Ensure the sizes sum to the size of the transaction.
+            assert_eq!(breakdown.total(), transaction.to_bytes_le().unwrap().len());
+            // Ensure the sizes match each field.
+            assert_eq!(breakdown.id(), transaction.id().to_bytes_le().unwrap().len());
+            match &transaction {
+                Transaction::Deploy(_, owner, deployment, _) => {
+                    assert_eq!(breakdown.header(), 2);
+                    assert_eq!(breakdown.owner(), owner.to_bytes_le().unwrap().len());
+                    assert_eq!(breakdown.deployment() as u64, deployment.size_in_bytes().unwrap());
+                    assert_eq!(breakdown.execution(), 0);
+                }
+                Transaction::Execute(_, execution, _) => {
+                    assert_eq!(breakdown.header(), 3);
+                    assert_eq!(breakdown.owner(), 0);
+                    assert_eq!(breakdown.deployment(), 0);
+                    assert_eq!(breakdown.execution() as u64, execution.size_in_bytes().unwrap());
+                }
+                Transaction::Fee(..) => {
+                    assert_eq!(breakdown.header(), 2);
+                    assert_eq!(breakdown.execution() + breakdown.deployment() + breakdown.owner(), 0);
+                }
+            }
+            let fee_size = transaction.fee_transition().map_or(0, |fee| fee.to_bytes_le().unwrap().len());
+            assert_eq!(breakdown.fee(), fee_size);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The error returned when an execute transaction has no transitions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmptyExecution<N: Network> {
+    /// The transaction ID.
+    pub id: N::TransactionID,
+}
+
+impl<N: Network> Display for EmptyExecution<N> {
+    /// Formats the empty execution error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Execute transaction '{}' has no transitions", self.id)
+    }
+}
+
+impl<N: Network> std::error::Error for EmptyExecution<N> {}
+
+impl<N: Network> Transaction<N> {
+    /// Ensures the transaction is structurally valid.
+    ///
+    /// An execute transaction must contain at least one transition, or an `EmptyExecution` error is returned.
+    /// Note: The constructors and the byte decoder already reject such transactions, but the enum variants
+    /// may be constructed directly, so this should be checked before accepting a transaction from elsewhere.
+    pub fn validate_structure(&self) -> Result<()> {
+        match self {
+            Self::Execute(id, execution, _) if execution.is_empty() => Err(EmptyExecution::<N> { id: *id }.into()),
+            Self::Deploy(..) | Self::Execute(..) | Self::Fee(..) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_validate_structure() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            transaction.validate_structure().unwrap();
+        }
+
+        // Construct an execute transaction with no transitions.
+        let id = <CurrentNetwork as Network>::TransactionID::from(Field::rand(rng));
+        let transaction = Transaction::<CurrentNetwork>::Execute(id, Execution::new(), None);
+        // Ensure it is rejected with an `EmptyExecution` error.
+        let error = transaction.validate_structure().unwrap_err();
+        assert_eq!(error.downcast_ref::<EmptyExecution<CurrentNetwork>>(), Some(&EmptyExecution { id }));
+        // Ensure it is rejected by the byte decoder.
+        let bytes = transaction.to_bytes_le().unwrap();
+        assert!(Transaction::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+    }
+}
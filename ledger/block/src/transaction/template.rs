@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use synthesizer_snark::Proof;
+
+/// The structure of a transaction, without its proofs.
+///
+/// As the transaction ID does not commit to the proofs, the template determines the transaction ID,
+/// and is combined with the proofs (produced separately) to assemble the transaction.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TransactionTemplate<N: Network> {
+    /// The transaction, without its proofs.
+    transaction: Transaction<N>,
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the template of the transaction, which is the transaction without its proofs.
+    pub fn to_template(&self) -> Result<TransactionTemplate<N>> {
+        Ok(TransactionTemplate { transaction: self.without_proofs()? })
+    }
+}
+
+impl<N: Network> TransactionTemplate<N> {
+    /// Returns the ID of the transaction.
+    pub const fn id(&self) -> N::TransactionID {
+        self.transaction.id()
+    }
+
+    /// Returns the transaction, without its proofs.
+    pub const fn transaction(&self) -> &Transaction<N> {
+        &self.transaction
+    }
+
+    /// Returns the transaction, with the given proofs.
+    ///
+    /// An execution proof must be given if, and only if, the template is an execute transaction,
+    /// and a fee proof must be given if, and only if, the template has a fee.
+    /// Note: This recomputes the transaction ID, but does *not* verify the proofs.
+    pub fn into_transaction(
+        self,
+        execution_proof: Option<Proof<N>>,
+        fee_proof: Option<Proof<N>>,
+    ) -> Result<Transaction<N>> {
+        let id = self.id();
+        // Returns the given fee, with the fee proof.
+        let with_proof = |fee: Fee<N>, fee_proof: Option<Proof<N>>| match fee_proof {
+            Some(proof) => Fee::from(fee.transition().clone(), fee.global_state_root(), Some(proof)),
+            None => bail!("Missing the fee proof for transaction '{id}'"),
+        };
+
+        // Construct the transaction.
+        let transaction = match self.transaction {
+            Transaction::Deploy(_, owner, deployment, fee) => {
+                ensure!(execution_proof.is_none(), "Found an execution proof for deployment transaction '{id}'");
+                Transaction::from_deployment(owner, *deployment, with_proof(fee, fee_proof)?)?
+            }
+            Transaction::Execute(_, execution, fee) => {
+                let Some(execution_proof) = execution_proof else {
+                    bail!("Missing the execution proof for execution transaction '{id}'")
+                };
+                let global_state_root = execution.global_state_root();
+                let execution =
+                    Execution::from(execution.into_transitions(), global_state_root, Some(execution_proof))?;
+                let fee = match fee {
+                    Some(fee) => Some(with_proof(fee, fee_proof)?),
+                    None => {
+                        ensure!(fee_proof.is_none(), "Found a fee proof for feeless transaction '{id}'");
+                        None
+                    }
+                };
+                Transaction::from_execution(execution, fee)?
+            }
+            Transaction::Fee(_, fee) => {
+                ensure!(execution_proof.is_none(), "Found an execution proof for fee transaction '{id}'");
+                Transaction::from_fee(with_proof(fee, fee_proof)?)?
+            }
+        };
+        // Ensure the transaction ID matches.
+        ensure!(transaction.id() == id, "Transaction ID mismatch - expected '{id}', found '{}'", transaction.id());
+        Ok(transaction)
+    }
+}
+
+impl<N: Network> Debug for TransactionTemplate<N> {
+    /// Prints the transaction template as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.transaction, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template() {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            // Construct the template.
+            let template = expected.to_template().unwrap();
+            assert_eq!(expected.id(), template.id());
+            assert!(template.transaction().execution().and_then(Execution::proof).is_none());
+            assert!(template.transaction().fee_transition().unwrap().proof().is_none());
+
+            // Ensure the template and proofs reassemble the transaction.
+            let execution_proof = expected.execution().and_then(Execution::proof).cloned();
+            let fee_proof = expected.fee_transition().and_then(|fee| fee.proof().cloned());
+            assert!(fee_proof.is_some());
+            let candidate = template.clone().into_transaction(execution_proof.clone(), fee_proof.clone()).unwrap();
+            assert_eq!(expected, candidate);
+
+            // Ensure a missing or unexpected proof is rejected.
+            assert!(template.clone().into_transaction(execution_proof.clone(), None).is_err());
+            let extra_proof = Some(execution_proof.clone().unwrap_or_else(|| fee_proof.clone().unwrap()));
+            match execution_proof {
+                Some(_) => assert!(template.clone().into_transaction(None, fee_proof).is_err()),
+                None => assert!(template.into_transaction(extra_proof, fee_proof).is_err()),
+            }
+        }
+    }
+}
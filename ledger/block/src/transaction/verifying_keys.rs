@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::Locator;
+
+/// The identifier of a verifying key, as the program ID and function name (i.e. `{program_id}/{function_name}`).
+/// Note: This is the key of the `IndexMap<Locator<N>, VerifyingKey<N>>` keyring in the process.
+pub type VerifyingKeyId<N> = Locator<N>;
+
+impl<N: Network> Transaction<N> {
+    /// Returns the deduplicated identifiers of the verifying keys required to verify the transaction,
+    /// in the order of `Transaction::transitions`, with the fee (if any) last.
+    ///
+    /// Note: The verifying keys of a deployment are carried in the deployment itself, and are not included,
+    /// so a deploy transaction requires only the verifying key of its fee.
+    pub fn required_verifying_keys(&self) -> Vec<VerifyingKeyId<N>> {
+        self.transitions()
+            .map(|transition| Locator::new(*transition.program_id(), *transition.function_name()))
+            .collect::<IndexSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_verifying_keys() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let keys = transaction.required_verifying_keys();
+            // Ensure the keys are deduplicated.
+            assert_eq!(keys.iter().collect::<IndexSet<_>>().len(), keys.len());
+            // Ensure there is a key for each transition.
+            for transition in transaction.transitions() {
+                assert!(keys.contains(&Locator::new(*transition.program_id(), *transition.function_name())));
+            }
+            // Ensure the fee key is last.
+            let fee = transaction.fee_transition().unwrap();
+            assert_eq!(keys.last(), Some(&Locator::new(*fee.program_id(), *fee.function_name())));
+            // Ensure a deploy transaction requires only the fee key.
+            if transaction.is_deploy() {
+                assert_eq!(keys.len(), 1);
+            }
+        }
+    }
+}
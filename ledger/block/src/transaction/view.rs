@@ -0,0 +1,271 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use synthesizer_snark::Proof;
+
+/// A view of an encoded transaction, which borrows the bytes of its proofs from the encoding.
+///
+/// The structure of the transaction is decoded, and its ID is checked, but the proofs are only decoded on demand.
+/// Note: The deployment (including its verifying keys) is decoded in full, as it determines the transaction ID.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TransactionView<'a, N: Network> {
+    /// The transaction, without its proofs.
+    transaction: Transaction<N>,
+    /// The encoded execution proof, if one exists.
+    execution_proof: Option<&'a [u8]>,
+    /// The encoded fee proof, if one exists.
+    fee_proof: Option<&'a [u8]>,
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns a view of the transaction encoded in the given bytes, which borrows the bytes of its proofs.
+    /// Note: The bytes must contain exactly one transaction, as produced by `Transaction::to_bytes_le`.
+    pub fn view_from_slice(bytes: &[u8]) -> Result<TransactionView<'_, N>> {
+        TransactionView::from_slice(bytes)
+    }
+}
+
+impl<'a, N: Network> TransactionView<'a, N> {
+    /// Returns a view of the transaction encoded in the given bytes, which borrows the bytes of its proofs.
+    pub fn from_slice(bytes: &'a [u8]) -> Result<Self> {
+        let mut reader = bytes;
+
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        ensure!(version == 1, "Invalid transaction version");
+
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Match the variant.
+        let (id, view) = match variant {
+            0 => {
+                // Read the ID.
+                let id = N::TransactionID::read_le(&mut reader)?;
+                // Read the owner.
+                let owner = ProgramOwner::read_le(&mut reader)?;
+                // Read the deployment.
+                let deployment = Deployment::read_le(&mut reader)?;
+                // Read the fee.
+                let (fee, fee_proof) = Self::read_fee(&mut reader)?;
+
+                // Initialize the transaction.
+                let transaction = Transaction::from_deployment(owner, deployment, fee)?;
+                (id, Self { transaction, execution_proof: None, fee_proof })
+            }
+            1 => {
+                // Read the ID.
+                let id = N::TransactionID::read_le(&mut reader)?;
+                // Read the execution.
+                let (execution, execution_proof) = Self::read_execution(&mut reader)?;
+                // Read the fee.
+                let (fee, fee_proof) = match u8::read_le(&mut reader)? {
+                    0 => (None, None),
+                    1 => {
+                        let (fee, fee_proof) = Self::read_fee(&mut reader)?;
+                        (Some(fee), fee_proof)
+                    }
+                    _ => bail!("Invalid fee variant"),
+                };
+
+                // Initialize the transaction.
+                let transaction = Transaction::from_execution(execution, fee)?;
+                (id, Self { transaction, execution_proof, fee_proof })
+            }
+            2 => {
+                // Read the ID.
+                let id = N::TransactionID::read_le(&mut reader)?;
+                // Read the fee.
+                let (fee, fee_proof) = Self::read_fee(&mut reader)?;
+
+                // Initialize the transaction.
+                let transaction = Transaction::from_fee(fee)?;
+                (id, Self { transaction, execution_proof: None, fee_proof })
+            }
+            3.. => bail!("Invalid transaction variant"),
+        };
+
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the transaction", reader.len());
+        // Ensure the transaction ID matches.
+        ensure!(view.transaction.id() == id, "Transaction ID mismatch");
+        Ok(view)
+    }
+
+    /// Returns the transaction ID.
+    pub const fn id(&self) -> N::TransactionID {
+        self.transaction.id()
+    }
+
+    /// Returns the transaction, without its proofs.
+    pub const fn transaction(&self) -> &Transaction<N> {
+        &self.transaction
+    }
+
+    /// Returns the encoded execution proof, if one exists.
+    pub const fn execution_proof_bytes(&self) -> Option<&'a [u8]> {
+        self.execution_proof
+    }
+
+    /// Returns the encoded fee proof, if one exists.
+    pub const fn fee_proof_bytes(&self) -> Option<&'a [u8]> {
+        self.fee_proof
+    }
+
+    /// Decodes the execution proof, if one exists.
+    pub fn execution_proof(&self) -> Result<Option<Proof<N>>> {
+        self.execution_proof.map(Proof::from_bytes_le).transpose()
+    }
+
+    /// Decodes the fee proof, if one exists.
+    pub fn fee_proof(&self) -> Result<Option<Proof<N>>> {
+        self.fee_proof.map(Proof::from_bytes_le).transpose()
+    }
+
+    /// Decodes the proofs, and returns the transaction.
+    pub fn into_transaction(self) -> Result<Transaction<N>> {
+        // Decode the proofs.
+        let (execution_proof, fee_proof) = (self.execution_proof()?, self.fee_proof()?);
+        // Returns the given fee, with the fee proof.
+        let with_proof =
+            |fee: Fee<N>| Fee::from_unchecked(fee.transition().clone(), fee.global_state_root(), fee_proof);
+
+        match self.transaction {
+            Transaction::Deploy(id, owner, deployment, fee) => {
+                Ok(Transaction::Deploy(id, owner, deployment, with_proof(fee)))
+            }
+            Transaction::Execute(id, execution, fee) => {
+                let global_state_root = execution.global_state_root();
+                let execution = Execution::from(execution.into_transitions(), global_state_root, execution_proof)?;
+                Ok(Transaction::Execute(id, execution, fee.map(with_proof)))
+            }
+            Transaction::Fee(id, fee) => Ok(Transaction::Fee(id, with_proof(fee))),
+        }
+    }
+
+    /// Reads an execution without its proof, and returns it with the encoded proof (if one exists).
+    fn read_execution(reader: &mut &'a [u8]) -> Result<(Execution<N>, Option<&'a [u8]>)> {
+        // Read the version.
+        let version = u8::read_le(&mut *reader)?;
+        // Ensure the version is valid.
+        ensure!(version == 1, "Invalid execution version");
+        // Read the number of transitions.
+        let num_transitions = u8::read_le(&mut *reader)?;
+        // Ensure the number of transitions is nonzero.
+        ensure!(num_transitions != 0, "Execution (from 'view') has no transitions");
+        // Read the transitions.
+        let transitions =
+            (0..num_transitions).map(|_| Transition::read_le(&mut *reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the global state root.
+        let global_state_root = N::StateRoot::read_le(&mut *reader)?;
+        // Read the proof.
+        let proof = Self::read_proof(reader)?;
+        // Return the execution, and the proof.
+        Ok((Execution::from(transitions.into_iter(), global_state_root, None)?, proof))
+    }
+
+    /// Reads a fee without its proof, and returns it with the encoded proof (if one exists).
+    fn read_fee(reader: &mut &'a [u8]) -> Result<(Fee<N>, Option<&'a [u8]>)> {
+        // Read the version.
+        let version = u8::read_le(&mut *reader)?;
+        // Ensure the version is valid.
+        ensure!(version == 1, "Invalid fee version");
+        // Read the transition.
+        let transition = Transition::read_le(&mut *reader)?;
+        // Read the global state root.
+        let global_state_root = N::StateRoot::read_le(&mut *reader)?;
+        // Read the proof.
+        let proof = Self::read_proof(reader)?;
+        // Return the fee, and the proof.
+        Ok((Fee::from(transition, global_state_root, None)?, proof))
+    }
+
+    /// Reads the proof variant, and borrows the encoded proof (if one exists).
+    fn read_proof(reader: &mut &'a [u8]) -> Result<Option<&'a [u8]>> {
+        // Read the proof variant.
+        match u8::read_le(&mut *reader)? {
+            0 => Ok(None),
+            1 => {
+                // Compute the length of the proof, without decoding it.
+                let len = Proof::<N>::encoded_len(reader)?;
+                // Borrow the proof.
+                let (proof, remaining) = reader.split_at(len);
+                *reader = remaining;
+                Ok(Some(proof))
+            }
+            proof_variant => bail!("Invalid proof variant '{proof_variant}'"),
+        }
+    }
+}
+
+impl<N: Network> Debug for TransactionView<'_, N> {
+    /// Prints the transaction view as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TransactionView")
+            .field("id", &self.id())
+            .field("execution_proof_len", &self.execution_proof.map(<[u8]>::len))
+            .field("fee_proof_len", &self.fee_proof.map(<[u8]>::len))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_view_from_slice() {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let bytes = expected.to_bytes_le().unwrap();
+            let view = Transaction::<CurrentNetwork>::view_from_slice(&bytes).unwrap();
+            assert_eq!(expected.id(), view.id());
+            assert!(expected.transitions().eq(view.transaction().transitions()));
+
+            // Ensure the proofs are borrowed from the bytes, and decode to the expected proofs.
+            let execution_proof = expected.execution().and_then(Execution::proof);
+            let fee_proof = expected.fee_transition().and_then(|fee| fee.proof().cloned());
+            for (proof_bytes, proof) in
+                [(view.execution_proof_bytes(), execution_proof.cloned()), (view.fee_proof_bytes(), fee_proof.clone())]
+            {
+                assert_eq!(proof_bytes.is_some(), proof.is_some());
+                if let (Some(proof_bytes), Some(proof)) = (proof_bytes, proof) {
+                    assert!(bytes.as_ptr_range().contains(&proof_bytes.as_ptr()));
+                    assert_eq!(proof_bytes, proof.to_bytes_le().unwrap());
+                }
+            }
+            assert_eq!(execution_proof, view.execution_proof().unwrap().as_ref());
+            assert_eq!(fee_proof, view.fee_proof().unwrap());
+
+            // Ensure the view decodes to the transaction.
+            assert_eq!(expected, view.into_transaction().unwrap());
+
+            // Ensure trailing and missing bytes are rejected.
+            let mut trailing = bytes.clone();
+            trailing.push(0);
+            assert!(Transaction::<CurrentNetwork>::view_from_slice(&trailing).is_err());
+            assert!(Transaction::<CurrentNetwork>::view_from_slice(&bytes[..bytes.len() - 1]).is_err());
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Transaction<N> {
+    /// The scheduling weight of every transaction, which accounts for decoding and checking its structure.
+    pub const SCHEDULING_WEIGHT_BASE: u64 = 1_000;
+    /// The scheduling weight of each byte of a deployment transaction, which accounts for synthesizing its functions.
+    pub const SCHEDULING_WEIGHT_PER_DEPLOYMENT_BYTE: u64 = 1;
+    /// The scheduling weight of each transition (including the fee), which accounts for verifying its proof.
+    pub const SCHEDULING_WEIGHT_PER_TRANSITION: u64 = 10_000;
+
+    /// Returns an estimate of the work to verify and execute the transaction, for weighted fair queueing.
+    ///
+    /// Note: This method serializes a deployment transaction to measure its size.
+    /// If the encoded length is already known, use `scheduling_weight_with_size` instead.
+    pub fn scheduling_weight(&self) -> u64 {
+        // Determine the size of a deployment transaction, saturating if it cannot be computed.
+        let num_bytes = match self {
+            Self::Deploy(..) => self.to_bytes_le().map_or(u64::MAX, |bytes| bytes.len() as u64),
+            Self::Execute(..) | Self::Fee(..) => 0,
+        };
+        self.scheduling_weight_with_size(num_bytes)
+    }
+
+    /// Returns an estimate of the work to verify and execute the transaction, for weighted fair queueing,
+    /// given the encoded length of the transaction in bytes.
+    ///
+    /// The weight is computed as:
+    /// `SCHEDULING_WEIGHT_BASE + SCHEDULING_WEIGHT_PER_TRANSITION * num_transitions
+    ///     + SCHEDULING_WEIGHT_PER_DEPLOYMENT_BYTE * num_bytes`,
+    /// where `num_bytes` only counts for a deployment transaction, and the weight saturates at `u64::MAX`.
+    /// The weight is monotonic in the number of transitions and the size of the deployment.
+    /// Note: Unlike the fee, the weight models the resources consumed, and not the priority of the transaction.
+    pub fn scheduling_weight_with_size(&self, num_bytes: u64) -> u64 {
+        // Determine the number of transitions.
+        let num_transitions = u64::try_from(self.transitions().count()).unwrap_or(u64::MAX);
+        // Only the size of a deployment reflects the work to verify it.
+        let num_deployment_bytes = match self {
+            Self::Deploy(..) => num_bytes,
+            Self::Execute(..) | Self::Fee(..) => 0,
+        };
+        // Compute the weight.
+        Self::SCHEDULING_WEIGHT_BASE
+            .saturating_add(Self::SCHEDULING_WEIGHT_PER_TRANSITION.saturating_mul(num_transitions))
+            .saturating_add(Self::SCHEDULING_WEIGHT_PER_DEPLOYMENT_BYTE.saturating_mul(num_deployment_bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_scheduling_weight() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the weight matches the formula.
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let num_transitions = transaction.transitions().count() as u64;
+            let num_bytes = transaction.to_bytes_le().unwrap().len() as u64;
+            let num_deployment_bytes = if transaction.is_deploy() { num_bytes } else { 0 };
+            let expected = Transaction::<CurrentNetwork>::SCHEDULING_WEIGHT_BASE
+                + Transaction::<CurrentNetwork>::SCHEDULING_WEIGHT_PER_TRANSITION * num_transitions
+                + Transaction::<CurrentNetwork>::SCHEDULING_WEIGHT_PER_DEPLOYMENT_BYTE * num_deployment_bytes;
+            assert_eq!(transaction.scheduling_weight(), expected);
+            assert_eq!(transaction.scheduling_weight_with_size(num_bytes), expected);
+        }
+
+        // Ensure the weight is monotonic in the number of transitions.
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let execution = transaction.execution().unwrap().clone();
+        let without_fee = Transaction::from_execution(execution, None).unwrap();
+        assert!(without_fee.scheduling_weight() < transaction.scheduling_weight());
+
+        // Ensure the weight is monotonic in the size of the deployment.
+        let transaction = crate::transaction::test_helpers::sample_deployment_transaction(true, rng);
+        let num_bytes = transaction.to_bytes_le().unwrap().len() as u64;
+        let weight = transaction.scheduling_weight_with_size(num_bytes);
+        assert!(weight < transaction.scheduling_weight_with_size(num_bytes + 1));
+    }
+}
@@ -27,10 +27,15 @@ use console::{
     network::prelude::*,
     program::{
         compute_function_id,
+        Argument,
         Ciphertext,
+        Future,
         Identifier,
         InputID,
+        Literal,
         OutputID,
+        Owner,
+        Plaintext,
         ProgramID,
         Record,
         Register,
@@ -43,7 +48,7 @@ use console::{
         ValueType,
         TRANSITION_DEPTH,
     },
-    types::{Field, Group},
+    types::{Address, Field, Group},
 };
 
 #[derive(Clone, PartialEq, Eq)]
@@ -335,6 +340,20 @@ impl<N: Network> Transition<N> {
             && self.function_name.to_string() == "unbond_public"
     }
 
+    /// Returns `true` if this is a call to one of the `credits.aleo` transfer functions.
+    #[inline]
+    pub fn is_credits_transfer(&self) -> bool {
+        self.program_id.to_string() == "credits.aleo"
+            && matches!(
+                self.function_name.to_string().as_str(),
+                "transfer_public"
+                    | "transfer_public_as_signer"
+                    | "transfer_private"
+                    | "transfer_private_to_public"
+                    | "transfer_public_to_private"
+            )
+    }
+
     /// Returns `true` if this is a `fee_private` transition.
     #[inline]
     pub fn is_fee_private(&self) -> bool {
@@ -403,6 +422,54 @@ impl<N: Network> Transition<N> {
     }
 }
 
+impl<N: Network> Transition<N> {
+    /// Returns `true` if the given address is in a public input or output of the transition.
+    /// This includes the arguments of the output future, and the owner of an output record with a public owner.
+    /// Note: Private inputs and outputs, and output records with a private owner, are not checked.
+    pub fn publicly_involves(&self, address: &Address<N>) -> bool {
+        // Returns `true` if the given plaintext contains the address.
+        fn plaintext_involves<N: Network>(plaintext: &Plaintext<N>, address: &Address<N>) -> bool {
+            match plaintext {
+                Plaintext::Literal(Literal::Address(candidate), _) => candidate == address,
+                Plaintext::Literal(_, _) => false,
+                Plaintext::Struct(members, _) => members.values().any(|member| plaintext_involves(member, address)),
+                Plaintext::Array(elements, _) => elements.iter().any(|element| plaintext_involves(element, address)),
+            }
+        }
+        // Returns `true` if the given future contains the address.
+        fn future_involves<N: Network>(future: &Future<N>, address: &Address<N>) -> bool {
+            future.arguments().iter().any(|argument| match argument {
+                Argument::Plaintext(plaintext) => plaintext_involves(plaintext, address),
+                Argument::Future(future) => future_involves(future, address),
+            })
+        }
+
+        let in_inputs = self.inputs.iter().any(|input| match input {
+            Input::Constant(_, Some(plaintext)) => plaintext_involves(plaintext, address),
+            Input::Public(_, Some(plaintext)) => plaintext_involves(plaintext, address),
+            Input::Constant(_, None) | Input::Public(_, None) => false,
+            Input::Private(_, _) => false,
+            Input::Record(_, _) => false,
+            Input::ExternalRecord(_) => false,
+        });
+        in_inputs
+            || self.outputs.iter().any(|output| match output {
+                Output::Constant(_, Some(plaintext)) => plaintext_involves(plaintext, address),
+                Output::Public(_, Some(plaintext)) => plaintext_involves(plaintext, address),
+                Output::Constant(_, None) | Output::Public(_, None) => false,
+                Output::Private(_, _) => false,
+                Output::Record(_, _, Some(record)) => match record.owner() {
+                    Owner::Public(owner) => owner == address,
+                    Owner::Private(_) => false,
+                },
+                Output::Record(_, _, None) => false,
+                Output::ExternalRecord(_) => false,
+                Output::Future(_, Some(future)) => future_involves(future, address),
+                Output::Future(_, None) => false,
+            })
+    }
+}
+
 impl<N: Network> Transition<N> {
     /* Input */
 
@@ -503,3 +570,28 @@ pub mod test_helpers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_publicly_involves() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the payer of a public fee is involved.
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        let payer = transaction.fee_transition().unwrap().payer().unwrap();
+        assert!(transaction.publicly_involves(&payer));
+        assert!(transaction.fee_paying_transition().unwrap().publicly_involves(&payer));
+
+        // Ensure an unrelated address is not involved.
+        let address = Address::<CurrentNetwork>::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert!(!transaction.publicly_involves(&address));
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        assert!(!transaction.publicly_involves(&address));
+    }
+}
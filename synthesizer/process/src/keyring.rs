@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A source of verifying keys, indexed by program ID and function name.
+pub trait Keyring<N: Network> {
+    /// Returns the verifying key for the given program ID and function name.
+    fn get_verifying_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<VerifyingKey<N>>;
+}
+
+impl<N: Network> Keyring<N> for Process<N> {
+    /// Returns the verifying key for the given program ID and function name, from the program stacks.
+    #[inline]
+    fn get_verifying_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<VerifyingKey<N>> {
+        self.get_stack(program_id)?.get_verifying_key(function_name)
+    }
+}
+
+impl<N: Network> Keyring<N> for IndexMap<Locator<N>, VerifyingKey<N>> {
+    /// Returns the verifying key for the given program ID and function name, from the map.
+    #[inline]
+    fn get_verifying_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<VerifyingKey<N>> {
+        match self.get(&Locator::new(*program_id, *function_name)) {
+            Some(verifying_key) => Ok(verifying_key.clone()),
+            None => bail!("Missing the verifying key for '{program_id}/{function_name}'"),
+        }
+    }
+}
+
+impl<N: Network, K: Keyring<N>> Keyring<N> for Arc<K> {
+    /// Returns the verifying key for the given program ID and function name, from the inner keyring.
+    #[inline]
+    fn get_verifying_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<VerifyingKey<N>> {
+        (**self).get_verifying_key(program_id, function_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_keyring_map() {
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Prepare the locator for 'credits.aleo/fee_public'.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("fee_public").unwrap();
+        let locator = Locator::new(program_id, function_name);
+
+        // Construct a keyring with the fee verifying key.
+        let verifying_key = Keyring::get_verifying_key(&process, &program_id, &function_name).unwrap();
+        let keyring = IndexMap::from([(locator, verifying_key.clone())]);
+
+        // Ensure the keyring returns the same verifying key as the process.
+        assert_eq!(verifying_key, keyring.get_verifying_key(&program_id, &function_name).unwrap());
+        // Ensure the keyring rejects a missing verifying key.
+        let function_name = Identifier::from_str("fee_private").unwrap();
+        assert!(keyring.get_verifying_key(&program_id, &function_name).is_err());
+    }
+}
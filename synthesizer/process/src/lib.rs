@@ -21,6 +21,12 @@
 mod cost;
 pub use cost::*;
 
+mod keyring;
+pub use keyring::*;
+
+mod proof_bundle;
+pub use proof_bundle::*;
+
 mod stack;
 pub use stack::*;
 
@@ -38,6 +44,8 @@ mod finalize;
 mod verify_deployment;
 mod verify_execution;
 mod verify_fee;
+mod verify_transaction;
+pub use verify_transaction::{VerificationTimeout, MAX_VERIFICATION_WORKERS};
 
 #[cfg(test)]
 mod tests;
@@ -48,7 +56,7 @@ use console::{
     program::{compute_function_id, Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, Value},
     types::{Field, U16, U64},
 };
-use ledger_block::{Deployment, Execution, Fee, Input, Transition};
+use ledger_block::{Deployment, Execution, Fee, Input, Transaction, TransactionTemplate, TransactionView, Transition};
 use ledger_store::{atomic_batch_scope, FinalizeStorage, FinalizeStore};
 use synthesizer_program::{
     Branch,
@@ -340,6 +348,24 @@ pub mod test_helpers {
         trace.prove_execution::<CurrentAleo, _>(&locator, rng).unwrap()
     }
 
+    /// Returns the `testing.aleo` program, whose `compute` function is used by `sample_key` and `sample_execution`.
+    pub(crate) fn sample_program() -> Program<CurrentNetwork> {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        program
+    }
+
     pub fn sample_key() -> (Identifier<CurrentNetwork>, ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>) {
         static INSTANCE: OnceCell<(
             Identifier<CurrentNetwork>,
@@ -349,18 +375,7 @@ pub mod test_helpers {
         INSTANCE
             .get_or_init(|| {
                 // Initialize a new program.
-                let (string, program) = Program::<CurrentNetwork>::parse(
-                    r"
-program testing.aleo;
-
-function compute:
-    input r0 as u32.private;
-    input r1 as u32.public;
-    add r0 r1 into r2;
-    output r2 as u32.public;",
-                )
-                .unwrap();
-                assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+                let program = sample_program();
 
                 // Declare the function name.
                 let function_name = Identifier::from_str("compute").unwrap();
@@ -388,18 +403,7 @@ function compute:
         INSTANCE
             .get_or_init(|| {
                 // Initialize a new program.
-                let (string, program) = Program::<CurrentNetwork>::parse(
-                    r"
-program testing.aleo;
-
-function compute:
-    input r0 as u32.private;
-    input r1 as u32.public;
-    add r0 r1 into r2;
-    output r2 as u32.public;",
-                )
-                .unwrap();
-                assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+                let program = sample_program();
 
                 // Declare the function name.
                 let function_name = Identifier::from_str("compute").unwrap();
@@ -455,4 +459,21 @@ function compute:
         // Return the process.
         process
     }
+
+    /// Returns a process with the `testing.aleo` program, and the circuit keys for its `compute` function.
+    pub(crate) fn sample_process_with_key() -> Process<CurrentNetwork> {
+        static INSTANCE: OnceCell<Process<CurrentNetwork>> = OnceCell::new();
+        INSTANCE
+            .get_or_init(|| {
+                // Construct the process.
+                let program = sample_program();
+                let process = sample_process(&program);
+                // Insert the proving and verifying key.
+                let (function_name, proving_key, verifying_key) = sample_key();
+                process.insert_proving_key(program.id(), &function_name, proving_key).unwrap();
+                process.insert_verifying_key(program.id(), &function_name, verifying_key).unwrap();
+                process
+            })
+            .clone()
+    }
 }
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> FromBytes for ProofInstance<N> {
+    /// Reads the proof instance from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid proof instance version"));
+        }
+        // Read the locator.
+        let locator_len = u16::read_le(&mut reader)?;
+        let mut locator = vec![0u8; locator_len as usize];
+        reader.read_exact(&mut locator)?;
+        let locator = String::from_utf8(locator).map_err(|e| error(e.to_string()))?;
+        // Read the number of functions.
+        let num_functions = u16::read_le(&mut reader)?;
+        // Ensure the number of functions is within bounds.
+        if num_functions as usize > Transaction::<N>::MAX_TRANSITIONS {
+            return Err(error("The proof instance exceeds the maximum number of functions"));
+        }
+        // Read the public inputs for each function.
+        let verifier_inputs = (0..num_functions)
+            .map(|_| Ok((Locator::read_le(&mut reader)?, read_public_inputs::<N, _>(&mut reader)?)))
+            .collect::<IoResult<IndexMap<_, _>>>()?;
+        // Ensure the locators are unique.
+        if verifier_inputs.len() != num_functions as usize {
+            return Err(error("The proof instance contains a duplicate function"));
+        }
+        // Read the public inputs for the inclusion circuit.
+        let inclusion_inputs = read_public_inputs::<N, _>(&mut reader)?;
+        // Read the proof.
+        let proof = Proof::read_le(&mut reader)?;
+        // Return the proof instance.
+        Ok(Self { locator, verifier_inputs, inclusion_inputs, proof })
+    }
+}
+
+impl<N: Network> ToBytes for ProofInstance<N> {
+    /// Writes the proof instance to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the locator.
+        u16::try_from(self.locator.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        writer.write_all(self.locator.as_bytes())?;
+        // Write the number of functions.
+        u16::try_from(self.verifier_inputs.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the public inputs for each function.
+        for (locator, inputs) in &self.verifier_inputs {
+            locator.write_le(&mut writer)?;
+            write_public_inputs::<N, _>(inputs, &mut writer)?;
+        }
+        // Write the public inputs for the inclusion circuit.
+        write_public_inputs::<N, _>(&self.inclusion_inputs, &mut writer)?;
+        // Write the proof.
+        self.proof.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for ProofBundle<N> {
+    /// Reads the proof bundle from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid proof bundle version"));
+        }
+        // Read the transaction ID.
+        let id = N::TransactionID::read_le(&mut reader)?;
+        // Read the execution proof.
+        let execution = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(ProofInstance::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid execution proof variant '{variant}'"))),
+        };
+        // Read the fee proof.
+        let fee = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(ProofInstance::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid fee proof variant '{variant}'"))),
+        };
+        // Return the proof bundle.
+        Ok(Self { id, execution, fee })
+    }
+}
+
+impl<N: Network> ToBytes for ProofBundle<N> {
+    /// Writes the proof bundle to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the transaction ID.
+        self.id.write_le(&mut writer)?;
+        // Write the execution proof and the fee proof.
+        for instance in [&self.execution, &self.fee] {
+            match instance {
+                None => 0u8.write_le(&mut writer)?,
+                Some(instance) => {
+                    1u8.write_le(&mut writer)?;
+                    instance.write_le(&mut writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the public inputs for a batch of instances from the buffer.
+fn read_public_inputs<N: Network, R: Read>(mut reader: R) -> IoResult<Vec<Vec<N::Field>>> {
+    // Read the number of instances.
+    let num_instances = u16::read_le(&mut reader)?;
+    // Ensure the number of instances is within bounds.
+    if num_instances as usize > Transaction::<N>::MAX_TRANSITIONS {
+        return Err(error("The proof instance exceeds the maximum number of instances"));
+    }
+    // Read the public inputs for each instance.
+    (0..num_instances)
+        .map(|_| {
+            let num_inputs = u16::read_le(&mut reader)?;
+            (0..num_inputs).map(|_| N::Field::read_le(&mut reader)).collect()
+        })
+        .collect()
+}
+
+/// Writes the public inputs for a batch of instances to the buffer.
+fn write_public_inputs<N: Network, W: Write>(inputs: &[Vec<N::Field>], mut writer: W) -> IoResult<()> {
+    // Write the number of instances.
+    u16::try_from(inputs.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+    // Write the public inputs for each instance.
+    for instance in inputs {
+        u16::try_from(instance.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        instance.iter().try_for_each(|input| input.write_le(&mut writer))?;
+    }
+    Ok(())
+}
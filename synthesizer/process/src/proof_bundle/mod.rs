@@ -0,0 +1,358 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+
+use super::*;
+
+use synthesizer_snark::Proof;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A batch proof, along with the public inputs required to verify it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofInstance<N: Network> {
+    /// The locator used to identify the proof in error messages.
+    locator: String,
+    /// The public inputs for each function in the batch, keyed by the locator of its verifying key.
+    verifier_inputs: IndexMap<Locator<N>, Vec<Vec<N::Field>>>,
+    /// The public inputs for the inclusion circuit in the batch.
+    inclusion_inputs: Vec<Vec<N::Field>>,
+    /// The batch proof.
+    proof: Proof<N>,
+}
+
+impl<N: Network> ProofInstance<N> {
+    /// Returns the public inputs for each function in the batch, keyed by the locator of its verifying key.
+    pub const fn verifier_inputs(&self) -> &IndexMap<Locator<N>, Vec<Vec<N::Field>>> {
+        &self.verifier_inputs
+    }
+
+    /// Returns the public inputs for the inclusion circuit in the batch.
+    pub const fn inclusion_inputs(&self) -> &Vec<Vec<N::Field>> {
+        &self.inclusion_inputs
+    }
+
+    /// Returns the batch proof.
+    pub const fn proof(&self) -> &Proof<N> {
+        &self.proof
+    }
+
+    /// Verifies the batch proof, using the given keyring to retrieve the verifying keys.
+    fn verify(&self, keyring: &impl Keyring<N>) -> Result<()> {
+        // Retrieve the verifying key for each function.
+        let verifier_inputs = self
+            .verifier_inputs
+            .iter()
+            .map(|(locator, inputs)| {
+                Ok((keyring.get_verifying_key(locator.program_id(), locator.resource())?, inputs.clone()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Verify the batch proof.
+        Trace::verify_batch_with_inclusion_inputs(
+            &self.locator,
+            verifier_inputs,
+            self.inclusion_inputs.clone(),
+            &self.proof,
+        )
+    }
+}
+
+/// The proofs in a transaction, along with the public inputs required to verify them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofBundle<N: Network> {
+    /// The transaction ID.
+    id: N::TransactionID,
+    /// The execution proof, if the transaction is an execute transaction.
+    execution: Option<ProofInstance<N>>,
+    /// The fee proof, if the transaction has a fee.
+    fee: Option<ProofInstance<N>>,
+}
+
+impl<N: Network> ProofBundle<N> {
+    /// Returns the ID of the transaction the bundle was constructed from.
+    pub const fn id(&self) -> &N::TransactionID {
+        &self.id
+    }
+
+    /// Returns the execution proof, if the transaction is an execute transaction.
+    pub const fn execution(&self) -> Option<&ProofInstance<N>> {
+        self.execution.as_ref()
+    }
+
+    /// Returns the fee proof, if the transaction has a fee.
+    pub const fn fee(&self) -> Option<&ProofInstance<N>> {
+        self.fee.as_ref()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the proof bundle for the given transaction.
+    ///
+    /// This performs the same well-formedness checks as `Process::verify_split`, without verifying the proofs.
+    /// For a deploy transaction, the bundle only contains the fee proof, as a deployment has no execution proof.
+    #[inline]
+    pub fn to_proof_bundle(&self, transaction: &Transaction<N>) -> Result<ProofBundle<N>> {
+        let (execution, fee) = match transaction {
+            Transaction::Deploy(id, _, deployment, fee) => {
+                // Compute the deployment ID.
+                let Ok(deployment_id) = deployment.to_deployment_id() else {
+                    bail!("Failed to compute the Merkle root for deployment transaction '{id}'")
+                };
+                (None, Some(self.to_fee_proof_instance(fee, deployment_id)?))
+            }
+            Transaction::Execute(id, execution, fee) => {
+                let fee = match fee {
+                    Some(fee) => {
+                        // Compute the execution ID.
+                        let Ok(execution_id) = execution.to_execution_id() else {
+                            bail!("Failed to compute the Merkle root for execution transaction '{id}'")
+                        };
+                        Some(self.to_fee_proof_instance(fee, execution_id)?)
+                    }
+                    None => None,
+                };
+                (Some(self.to_execution_proof_instance(execution)?), fee)
+            }
+            Transaction::Fee(_, fee) => {
+                (None, Some(self.to_fee_proof_instance(fee, fee.deployment_or_execution_id()?)?))
+            }
+        };
+        Ok(ProofBundle { id: transaction.id(), execution, fee })
+    }
+
+    /// Returns the proof instance for the given execution.
+    fn to_execution_proof_instance(&self, execution: &Execution<N>) -> Result<ProofInstance<N>> {
+        // Construct the verifier inputs for the execution.
+        let (locator, verifier_inputs) = self.prepare_execution_verifier_inputs(execution)?;
+        // Retrieve the global state root.
+        let global_state_root = execution.global_state_root();
+        // Ensure the global state root is not zero.
+        if global_state_root == N::StateRoot::default() {
+            bail!("Inclusion expected the global state root in the execution to *not* be zero")
+        }
+        // Retrieve the proof.
+        let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
+        // Construct the batch of inclusion verifier inputs.
+        let inclusion_inputs = Trace::prepare_inclusion_verifier_inputs(global_state_root, execution.transitions())?;
+        Ok(ProofInstance { locator, verifier_inputs, inclusion_inputs, proof: proof.clone() })
+    }
+
+    /// Returns the proof instance for the given fee.
+    fn to_fee_proof_instance(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<ProofInstance<N>> {
+        // Construct the verifier inputs for the fee.
+        let inputs = self.prepare_fee_verifier_inputs(fee, deployment_or_execution_id)?;
+        // Retrieve the global state root.
+        let global_state_root = fee.global_state_root();
+        // Ensure the global state root is not zero.
+        if global_state_root == N::StateRoot::default() {
+            bail!("Inclusion expected the global state root in the fee to *not* be zero")
+        }
+        // Retrieve the proof.
+        let Some(proof) = fee.proof() else { bail!("Expected the fee to contain a proof") };
+        // Construct the batch of inclusion verifier inputs.
+        let inclusion_inputs =
+            Trace::prepare_inclusion_verifier_inputs(global_state_root, [fee.transition()].into_iter())?;
+        Ok(ProofInstance {
+            locator: "credits.aleo/fee (private or public)".to_string(),
+            verifier_inputs: IndexMap::from([(Locator::new(*fee.program_id(), *fee.function_name()), vec![inputs])]),
+            inclusion_inputs,
+            proof: proof.clone(),
+        })
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the public inputs of each transition in the given transaction, in transition order.
+    ///
+    /// The public inputs of a transition are the field elements passed to the verifier for its instance in the
+    /// batch proof, in the order they are consumed: `1`, the `tpk` coordinates, `tcm`, `scm`, the input IDs,
+    /// the `is_root` flag and parent coordinates, the commitments and IDs of the calls, and the output IDs.
+    /// Note: These are the verifier inputs as given to Varuna, which absorbs them into its Fiat-Shamir transcript.
+    pub fn public_inputs_by_transition(&self, transaction: &Transaction<N>) -> Result<Vec<Vec<Field<N>>>> {
+        // Returns the given verifier inputs, as field elements.
+        let to_fields = |inputs: Vec<N::Field>| inputs.into_iter().map(Field::new).collect::<Vec<_>>();
+
+        let mut public_inputs = Vec::new();
+        // Retrieve the public inputs for the execution.
+        if let Some(execution) = transaction.execution() {
+            // Retrieve the public inputs for each function, in transition order.
+            let (_, verifier_inputs) = self.prepare_execution_verifier_inputs(execution)?;
+            let mut verifier_inputs = verifier_inputs
+                .into_iter()
+                .map(|(locator, inputs)| (locator, inputs.into_iter()))
+                .collect::<IndexMap<_, _>>();
+            for transition in execution.transitions() {
+                let locator = Locator::new(*transition.program_id(), *transition.function_name());
+                let Some(inputs) = verifier_inputs.get_mut(&locator).and_then(Iterator::next) else {
+                    bail!("Missing the public inputs for transition '{}'", transition.id())
+                };
+                public_inputs.push(to_fields(inputs));
+            }
+        }
+        // Retrieve the public inputs for the fee.
+        if let Some(fee) = transaction.fee_transition() {
+            let deployment_or_execution_id = match transaction {
+                Transaction::Deploy(_, _, deployment, _) => deployment.to_deployment_id()?,
+                Transaction::Execute(_, execution, _) => execution.to_execution_id()?,
+                Transaction::Fee(..) => fee.deployment_or_execution_id()?,
+            };
+            public_inputs.push(to_fields(self.prepare_fee_verifier_inputs(&fee, deployment_or_execution_id)?));
+        }
+        Ok(public_inputs)
+    }
+
+    /// Returns the transaction assembled from the given template and proof bundle.
+    ///
+    /// This is the inverse of `Transaction::to_template` and `Process::to_proof_bundle`. The bundle is checked
+    /// to be consistent with the assembled transaction, by recomputing the public inputs of its proofs.
+    /// Note: This does *not* verify the proofs, which is done with `verify_proof_bundle`.
+    pub fn from_template_and_bundle(
+        &self,
+        template: TransactionTemplate<N>,
+        bundle: ProofBundle<N>,
+    ) -> Result<Transaction<N>> {
+        // Ensure the bundle is for the template.
+        ensure!(
+            template.id() == bundle.id,
+            "The proof bundle for '{}' does not match the template for '{}'",
+            bundle.id,
+            template.id()
+        );
+        // Assemble the transaction.
+        let execution_proof = bundle.execution.as_ref().map(|execution| execution.proof.clone());
+        let fee_proof = bundle.fee.as_ref().map(|fee| fee.proof.clone());
+        let transaction = template.into_transaction(execution_proof, fee_proof)?;
+        // Ensure the public inputs in the bundle are consistent with the transaction.
+        ensure!(
+            self.to_proof_bundle(&transaction)? == bundle,
+            "The proof bundle for '{}' is inconsistent with the template",
+            bundle.id
+        );
+        Ok(transaction)
+    }
+}
+
+/// Verifies the proofs in the given proof bundle, using the given keyring to retrieve the verifying keys.
+///
+/// This is equivalent to calling `Process::verify_split` on the original transaction,
+/// with the given keyring for both the fee and the execution.
+/// Note: This does *not* check that the global state root exists in the ledger.
+pub fn verify_proof_bundle<N: Network>(bundle: &ProofBundle<N>, keyring: &impl Keyring<N>) -> Result<()> {
+    verify_proof_bundle_until(bundle, keyring, &AtomicBool::new(false))
+}
+
+/// Verifies the proofs in the given proof bundle, using the given keyring to retrieve the verifying keys.
+/// If `is_cancelled` is set, the remaining proofs are skipped, and an error is returned.
+///
+/// Note: Cancellation is checked before each proof, so a proof that is being verified runs to completion.
+pub(crate) fn verify_proof_bundle_until<N: Network>(
+    bundle: &ProofBundle<N>,
+    keyring: &impl Keyring<N>,
+    is_cancelled: &AtomicBool,
+) -> Result<()> {
+    let timer = timer!("verify_proof_bundle");
+
+    // Ensure the bundle contains a proof.
+    ensure!(bundle.execution.is_some() || bundle.fee.is_some(), "The proof bundle for '{}' is empty", bundle.id);
+
+    // Verify the execution proof, if it exists.
+    if let Some(execution) = &bundle.execution {
+        ensure!(!is_cancelled.load(Ordering::SeqCst), "Cancelled verification of the execution for '{}'", bundle.id);
+        if let Err(e) = execution.verify(keyring) {
+            bail!("Execution is invalid - {e}")
+        }
+        lap!(timer, "Verify the execution");
+    }
+    // Verify the fee proof, if it exists.
+    if let Some(fee) = &bundle.fee {
+        ensure!(!is_cancelled.load(Ordering::SeqCst), "Cancelled verification of the fee for '{}'", bundle.id);
+        if let Err(e) = fee.verify(keyring) {
+            bail!("Fee is invalid - {e}")
+        }
+        lap!(timer, "Verify the fee");
+    }
+
+    finish!(timer);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_verify_proof_bundle() {
+        // Sample an execution, and construct a feeless execution transaction.
+        let execution = test_helpers::sample_execution();
+        let transaction = Transaction::from_execution(execution.clone(), None).unwrap();
+
+        // Construct a process with the executed program, and the verifying key for the executed function.
+        let process = test_helpers::sample_process_with_key();
+        let transition = execution.peek().unwrap();
+
+        // Construct the proof bundle.
+        let bundle = process.to_proof_bundle(&transaction).unwrap();
+        assert_eq!(bundle.id(), &transaction.id());
+        assert!(bundle.execution().is_some());
+        assert!(bundle.fee().is_none());
+
+        // Construct a keyring with only the execution verifying key.
+        let verifying_key = process.get_verifying_key(transition.program_id(), transition.function_name()).unwrap();
+        let keyring =
+            IndexMap::from([(Locator::new(*transition.program_id(), *transition.function_name()), verifying_key)]);
+
+        // Ensure the bundle verifies, as the transaction does.
+        verify_proof_bundle(&bundle, &keyring).unwrap();
+        process.verify_split(&transaction, &keyring, &keyring).unwrap();
+        // Ensure the bundle fails to verify when the verifying key is missing.
+        assert!(verify_proof_bundle(&bundle, &IndexMap::new()).is_err());
+
+        // Ensure there are public inputs for the transition, which match the verifier inputs in the bundle.
+        let public_inputs = process.public_inputs_by_transition(&transaction).unwrap();
+        assert_eq!(public_inputs.len(), transaction.transitions().count());
+        let inputs = &bundle.execution().unwrap().verifier_inputs().values().next().unwrap()[0];
+        assert_eq!(public_inputs[0], inputs.iter().copied().map(Field::new).collect::<Vec<_>>());
+        // Ensure the public inputs start with `1`, followed by the transition public key.
+        assert_eq!(public_inputs[0][0], Field::one());
+        assert_eq!(public_inputs[0][1], transition.tpk().to_x_coordinate());
+
+        // Ensure the bundle round-trips through bytes, JSON, and bincode.
+        let bytes = bundle.to_bytes_le().unwrap();
+        assert_eq!(bundle, ProofBundle::read_le(&bytes[..]).unwrap());
+        let json = serde_json::to_string(&bundle).unwrap();
+        assert_eq!(bundle, serde_json::from_str(&json).unwrap());
+        let bincode = bincode::serialize(&bundle).unwrap();
+        assert_eq!(bundle, bincode::deserialize(&bincode).unwrap());
+        // Ensure the execution proof instance round-trips through bytes and JSON.
+        let instance = bundle.execution().unwrap();
+        assert_eq!(instance, &ProofInstance::read_le(&instance.to_bytes_le().unwrap()[..]).unwrap());
+        assert_eq!(instance, &serde_json::from_str(&serde_json::to_string(instance).unwrap()).unwrap());
+        // Ensure the decoded bundle verifies.
+        verify_proof_bundle(&ProofBundle::<CurrentNetwork>::read_le(&bytes[..]).unwrap(), &keyring).unwrap();
+        // Ensure a truncated bundle is rejected.
+        assert!(ProofBundle::<CurrentNetwork>::read_le(&bytes[..bytes.len() - 1]).is_err());
+
+        // Ensure the template and bundle reassemble the transaction.
+        let template = transaction.to_template().unwrap();
+        assert_eq!(transaction, process.from_template_and_bundle(template.clone(), bundle.clone()).unwrap());
+        // Ensure a bundle without the execution proof is rejected.
+        let incomplete = ProofBundle { execution: None, ..bundle };
+        assert!(process.from_template_and_bundle(template, incomplete).is_err());
+    }
+}
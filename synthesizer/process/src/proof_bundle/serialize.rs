@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Serialize for ProofInstance<N> {
+    /// Serializes the proof instance into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                // Returns the given public inputs, as console field elements.
+                let to_fields = |inputs: &Vec<Vec<N::Field>>| {
+                    inputs.iter().map(|inputs| inputs.iter().copied().map(Field::new).collect()).collect()
+                };
+                let verifier_inputs = self
+                    .verifier_inputs
+                    .iter()
+                    .map(|(locator, inputs)| (*locator, to_fields(inputs)))
+                    .collect::<Vec<(Locator<N>, Vec<Vec<Field<N>>>)>>();
+                let inclusion_inputs: Vec<Vec<Field<N>>> = to_fields(&self.inclusion_inputs);
+
+                let mut instance = serializer.serialize_struct("ProofInstance", 4)?;
+                instance.serialize_field("locator", &self.locator)?;
+                instance.serialize_field("verifier_inputs", &verifier_inputs)?;
+                instance.serialize_field("inclusion_inputs", &inclusion_inputs)?;
+                instance.serialize_field("proof", &self.proof)?;
+                instance.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProofInstance<N> {
+    /// Deserializes the proof instance from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Returns the given public inputs, as base field elements.
+                let from_fields = |inputs: Vec<Vec<Field<N>>>| -> Vec<Vec<N::Field>> {
+                    inputs.into_iter().map(|inputs| inputs.into_iter().map(|input| *input).collect()).collect()
+                };
+
+                // Parse the proof instance from a string into a value.
+                let mut instance = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the locator.
+                let locator = DeserializeExt::take_from_value::<D>(&mut instance, "locator")?;
+                // Retrieve the public inputs for each function.
+                let verifier_inputs: Vec<(Locator<N>, Vec<Vec<Field<N>>>)> =
+                    DeserializeExt::take_from_value::<D>(&mut instance, "verifier_inputs")?;
+                let num_functions = verifier_inputs.len();
+                let verifier_inputs = verifier_inputs
+                    .into_iter()
+                    .map(|(locator, inputs)| (locator, from_fields(inputs)))
+                    .collect::<IndexMap<_, _>>();
+                // Ensure the locators are unique.
+                if verifier_inputs.len() != num_functions {
+                    return Err(de::Error::custom("The proof instance contains a duplicate function"));
+                }
+                // Retrieve the public inputs for the inclusion circuit.
+                let inclusion_inputs = DeserializeExt::take_from_value::<D>(&mut instance, "inclusion_inputs")?;
+                let inclusion_inputs = from_fields(inclusion_inputs);
+                // Retrieve the proof.
+                let proof = DeserializeExt::take_from_value::<D>(&mut instance, "proof")?;
+                // Recover the proof instance.
+                Ok(Self { locator, verifier_inputs, inclusion_inputs, proof })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proof instance"),
+        }
+    }
+}
+
+impl<N: Network> Serialize for ProofBundle<N> {
+    /// Serializes the proof bundle into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut bundle = serializer.serialize_struct("ProofBundle", 3)?;
+                bundle.serialize_field("id", &self.id)?;
+                bundle.serialize_field("execution", &self.execution)?;
+                bundle.serialize_field("fee", &self.fee)?;
+                bundle.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProofBundle<N> {
+    /// Deserializes the proof bundle from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the proof bundle from a string into a value.
+                let mut bundle = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the transaction ID.
+                let id = DeserializeExt::take_from_value::<D>(&mut bundle, "id")?;
+                // Retrieve the execution proof.
+                let execution = DeserializeExt::take_from_value::<D>(&mut bundle, "execution")?;
+                // Retrieve the fee proof.
+                let fee = DeserializeExt::take_from_value::<D>(&mut bundle, "fee")?;
+                // Recover the proof bundle.
+                Ok(Self { id, execution, fee })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proof bundle"),
+        }
+    }
+}
@@ -299,13 +299,32 @@ impl<N: Network> Trace<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     fn verify_batch<'a>(
         locator: &str,
-        mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         proof: &Proof<N>,
     ) -> Result<()> {
         // Construct the batch of inclusion verifier inputs.
         let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
+        // Verify the proof.
+        Self::verify_batch_with_inclusion_inputs(locator, verifier_inputs, batch_inclusion_inputs, proof)
+    }
+
+    /// Returns the batch of inclusion verifier inputs for the given global state root and transitions.
+    pub(crate) fn prepare_inclusion_verifier_inputs<'a>(
+        global_state_root: N::StateRoot,
+        transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
+    ) -> Result<Vec<Vec<N::Field>>> {
+        Inclusion::prepare_verifier_inputs(global_state_root, transitions)
+    }
+
+    /// Checks the proof for the given verifier inputs and batch of inclusion verifier inputs.
+    pub(crate) fn verify_batch_with_inclusion_inputs(
+        locator: &str,
+        mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        batch_inclusion_inputs: Vec<Vec<N::Field>>,
+        proof: &Proof<N>,
+    ) -> Result<()> {
         // Insert the batch of inclusion verifier inputs to the verifier inputs.
         if !batch_inclusion_inputs.is_empty() {
             // Retrieve the inclusion verifying key.
@@ -19,8 +19,41 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
+        self.verify_execution_with_keyring(execution, self)
+    }
+
+    /// Verifies the given execution is valid, using the given keyring to retrieve the verifying keys.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_execution_with_keyring(&self, execution: &Execution<N>, keyring: &impl Keyring<N>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
+        // Construct the verifier inputs for the execution.
+        let (locator, verifier_inputs) = self.prepare_execution_verifier_inputs(execution)?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Retrieve the verifying key for each function.
+        let verifier_inputs = verifier_inputs
+            .into_iter()
+            .map(|(locator, inputs)| Ok((keyring.get_verifying_key(locator.program_id(), locator.resource())?, inputs)))
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Retrieve the verifying keys");
+
+        // Verify the execution proof.
+        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
+
+        finish!(timer, "Verify the proof");
+        Ok(())
+    }
+
+    /// Checks the given execution is well-formed, and returns the locator of the main function,
+    /// along with the public inputs to verify the execution proof, for each function.
+    pub(crate) fn prepare_execution_verifier_inputs(
+        &self,
+        execution: &Execution<N>,
+    ) -> Result<(String, IndexMap<Locator<N>, Vec<Vec<N::Field>>>)> {
+        let timer = timer!("Process::prepare_execution_verifier_inputs");
+
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
@@ -48,8 +81,8 @@ impl<N: Network> Process<N> {
         // Note: This is a mapping of the child transition ID to the parent transition ID.
         let reverse_call_graph = Self::reverse_call_graph(&call_graph);
 
-        // Initialize a map of verifying keys to public inputs.
-        let mut verifier_inputs = HashMap::new();
+        // Initialize a map of function locators to public inputs.
+        let mut verifier_inputs = IndexMap::new();
 
         // Initialize a map of transition IDs to references of the transition.
         let mut transition_map = HashMap::new();
@@ -114,12 +147,10 @@ impl<N: Network> Process<N> {
             let inputs = self.to_transition_verifier_inputs(transition, parent, &call_graph, &mut transition_map)?;
             lap!(timer, "Constructed the verifier inputs for a transition of {}", function.name());
 
-            // Save the verifying key and its inputs.
+            // Save the inputs for the function.
             verifier_inputs
                 .entry(Locator::new(*stack.program_id(), *function.name()))
-                // Retrieve the verifying key, if it does not already exist.
-                .or_insert((stack.get_verifying_key(function.name())?, vec![]))
-                .1
+                .or_insert_with(Vec::new)
                 .push(inputs);
             lap!(timer, "Stored the verifier inputs for a transition of {}", function.name());
 
@@ -128,7 +159,7 @@ impl<N: Network> Process<N> {
         }
 
         // Count the number of verifier instances.
-        let num_instances = verifier_inputs.values().map(|(_, inputs)| inputs.len()).sum::<usize>();
+        let num_instances = verifier_inputs.values().map(|inputs| inputs.len()).sum::<usize>();
         // Ensure the number of instances matches the number of transitions.
         ensure!(num_instances == execution.transitions().len(), "The number of verifier instances is incorrect");
         // Ensure the same signer is used for all transitions.
@@ -142,15 +173,10 @@ impl<N: Network> Process<N> {
             })
         })?;
 
-        // Construct the list of verifier inputs.
-        let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
-        // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
-
-        lap!(timer, "Verify the proof");
+        lap!(timer, "Verify the signer");
 
         finish!(timer);
-        Ok(())
+        Ok((locator, verifier_inputs))
     }
 }
 
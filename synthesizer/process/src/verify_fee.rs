@@ -19,8 +19,41 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+        self.verify_fee_with_keyring(fee, deployment_or_execution_id, self)
+    }
+
+    /// Verifies the given fee is valid, using the given keyring to retrieve the verifying key.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_fee_with_keyring(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        keyring: &impl Keyring<N>,
+    ) -> Result<()> {
         let timer = timer!("Process::verify_fee");
 
+        // Construct the verifier inputs for the fee.
+        let inputs = self.prepare_fee_verifier_inputs(fee, deployment_or_execution_id)?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Retrieve the verifying key.
+        let verifying_key = keyring.get_verifying_key(fee.program_id(), fee.function_name())?;
+
+        // Ensure the fee proof is valid.
+        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
+        finish!(timer, "Verify the fee proof");
+        Ok(())
+    }
+
+    /// Checks the given fee is well-formed, and returns the public inputs to verify the fee proof.
+    pub(crate) fn prepare_fee_verifier_inputs(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+    ) -> Result<Vec<N::Field>> {
+        let timer = timer!("Process::prepare_fee_verifier_inputs");
+
         #[cfg(debug_assertions)]
         {
             println!("Verifying fee from {}/{}...", fee.program_id(), fee.function_name());
@@ -61,19 +94,20 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Verify the deployment or execution ID");
 
-        // Verify the fee transition is well-formed.
-        match is_fee_private {
-            true => self.verify_fee_private(&fee)?,
-            false => self.verify_fee_public(&fee)?,
-        }
+        // Verify the fee transition is well-formed, and construct the verifier inputs.
+        let inputs = match is_fee_private {
+            true => self.prepare_fee_private_verifier_inputs(fee)?,
+            false => self.prepare_fee_public_verifier_inputs(fee)?,
+        };
         finish!(timer, "Verify the fee transition");
-        Ok(())
+        Ok(inputs)
     }
 }
 
 impl<N: Network> Process<N> {
-    /// Verifies the transition for `credits.aleo/fee_private` is well-formed.
-    fn verify_fee_private(&self, fee: &&Fee<N>) -> Result<()> {
+    /// Verifies the transition for `credits.aleo/fee_private` is well-formed,
+    /// and returns the public inputs to verify the fee proof.
+    fn prepare_fee_private_verifier_inputs(&self, fee: &Fee<N>) -> Result<Vec<N::Field>> {
         let timer = timer!("Process::verify_fee_private");
 
         // Retrieve the network ID.
@@ -126,23 +160,18 @@ impl<N: Network> Process<N> {
         inputs.extend([*Field::<N>::one(), *parent_x, *parent_y]);
         // Extend the inputs with the output IDs.
         inputs.extend(fee.outputs().iter().flat_map(|output| output.verifier_inputs()));
-        lap!(timer, "Construct the verifier inputs");
+        finish!(timer, "Construct the verifier inputs");
 
         #[cfg(debug_assertions)]
         println!("Fee public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
-        // Retrieve the verifying key.
-        let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
-
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        Ok(inputs)
     }
 
-    /// Verifies the transition for `credits.aleo/fee_public` is well-formed.
+    /// Verifies the transition for `credits.aleo/fee_public` is well-formed,
+    /// and returns the public inputs to verify the fee proof.
     /// Attention: This method does *not* verify the account balance is sufficient.
-    fn verify_fee_public(&self, fee: &&Fee<N>) -> Result<()> {
+    fn prepare_fee_public_verifier_inputs(&self, fee: &Fee<N>) -> Result<Vec<N::Field>> {
         let timer = timer!("Process::verify_fee_public");
 
         // Retrieve the network ID.
@@ -195,18 +224,12 @@ impl<N: Network> Process<N> {
         inputs.extend([*Field::<N>::one(), *parent_x, *parent_y]);
         // Extend the inputs with the output IDs.
         inputs.extend(fee.outputs().iter().flat_map(|output| output.verifier_inputs()));
-        lap!(timer, "Construct the verifier inputs");
+        finish!(timer, "Construct the verifier inputs");
 
         #[cfg(debug_assertions)]
         println!("Fee public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
-        // Retrieve the verifying key.
-        let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
-
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        Ok(inputs)
     }
 }
 
@@ -214,8 +237,11 @@ impl<N: Network> Process<N> {
 mod tests {
     use super::*;
     use console::prelude::TestRng;
+    use console::network::MainnetV0;
     use ledger_block::Transaction;
 
+    type CurrentNetwork = MainnetV0;
+
     #[test]
     fn test_verify_fee() {
         let rng = &mut TestRng::default();
@@ -247,11 +273,52 @@ mod tests {
                     // Verify the fee.
                     process.verify_fee(&fee.unwrap(), execution_id).unwrap();
                 }
-                Transaction::Fee(_, fee) => match fee.is_fee_private() {
-                    true => process.verify_fee_private(&&fee).unwrap(),
-                    false => process.verify_fee_public(&&fee).unwrap(),
-                },
+                Transaction::Fee(_, fee) => {
+                    // Retrieve the deployment or execution ID.
+                    let deployment_or_execution_id = fee.deployment_or_execution_id().unwrap();
+                    // Verify the fee.
+                    process.verify_fee(&fee, deployment_or_execution_id).unwrap();
+                }
             }
         }
     }
+
+    #[test]
+    fn test_verify_fee_with_keyring() {
+        let rng = &mut TestRng::default();
+
+        // Fetch transactions, with a private fee and a public fee.
+        let transactions = [
+            ledger_test_helpers::sample_execution_transaction_with_fee(true, rng),
+            ledger_test_helpers::sample_fee_public_transaction(rng),
+        ];
+
+        // Construct a new process.
+        let process = Process::load().unwrap();
+
+        for transaction in transactions {
+            // Retrieve the fee, and the ID it pays for.
+            let fee = transaction.fee_transition().unwrap();
+            let deployment_or_execution_id = fee.deployment_or_execution_id().unwrap();
+
+            // Construct a keyring with only the fee verifying key.
+            let verifying_key = Keyring::get_verifying_key(&process, fee.program_id(), fee.function_name()).unwrap();
+            let fee_keys = IndexMap::from([(Locator::new(*fee.program_id(), *fee.function_name()), verifying_key)]);
+            // Ensure the fee verifies against the keyring.
+            process.verify_fee_with_keyring(&fee, deployment_or_execution_id, &fee_keys).unwrap();
+
+            // Ensure the fee is rejected when the keyring lacks the fee verifying key.
+            let empty_keys = IndexMap::<Locator<CurrentNetwork>, VerifyingKey<CurrentNetwork>>::new();
+            assert!(process.verify_fee_with_keyring(&fee, deployment_or_execution_id, &empty_keys).is_err());
+
+            // Ensure the fee is rejected when the keyring holds the verifying key of the other fee function.
+            let other_name = match fee.is_fee_private() {
+                true => Identifier::from_str("fee_public").unwrap(),
+                false => Identifier::from_str("fee_private").unwrap(),
+            };
+            let other_key = Keyring::get_verifying_key(&process, fee.program_id(), &other_name).unwrap();
+            let wrong_keys = IndexMap::from([(Locator::new(*fee.program_id(), *fee.function_name()), other_key)]);
+            assert!(process.verify_fee_with_keyring(&fee, deployment_or_execution_id, &wrong_keys).is_err());
+        }
+    }
 }
@@ -0,0 +1,450 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use parking_lot::{Condvar, Mutex};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    time::{Duration, Instant},
+};
+
+/// The maximum number of verification workers that may run at once, across all calls to `verify_with_timeout`.
+/// Note: A worker whose call has timed out holds its slot until it completes the proof in progress.
+pub const MAX_VERIFICATION_WORKERS: usize = 4;
+
+/// The slots for the verification workers.
+static VERIFICATION_WORKERS: WorkerSlots = WorkerSlots::new(MAX_VERIFICATION_WORKERS);
+
+/// The error returned when a transaction is not verified within the given timeout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationTimeout<N: Network> {
+    /// The transaction ID.
+    pub id: N::TransactionID,
+    /// The timeout that was exceeded.
+    pub timeout: Duration,
+}
+
+impl<N: Network> Display for VerificationTimeout<N> {
+    /// Formats the timeout error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Verification of transaction '{}' exceeded the timeout of {:?}", self.id, self.timeout)
+    }
+}
+
+impl<N: Network> std::error::Error for VerificationTimeout<N> {}
+
+impl<N: Network> Process<N> {
+    /// Verifies the proofs in the given transaction, where the fee proof is verified against `fee_keys`
+    /// and the execution proof is verified against `execution_keys`.
+    ///
+    /// For a deploy transaction, only the fee is verified, as a deployment has no execution proof.
+    /// To verify the deployment itself, use `Process::verify_deployment`.
+    /// For a fee transaction, the fee is verified against the deployment or execution ID it claims to pay for.
+    ///
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_split(
+        &self,
+        transaction: &Transaction<N>,
+        fee_keys: &impl Keyring<N>,
+        execution_keys: &impl Keyring<N>,
+    ) -> Result<()> {
+        let timer = timer!("Process::verify_split");
+
+        match transaction {
+            Transaction::Deploy(id, _, deployment, fee) => {
+                // Compute the deployment ID.
+                let Ok(deployment_id) = deployment.to_deployment_id() else {
+                    bail!("Failed to compute the Merkle root for deployment transaction '{id}'")
+                };
+                // Verify the fee.
+                self.verify_fee_with_keyring(fee, deployment_id, fee_keys)?;
+                lap!(timer, "Verify the fee");
+            }
+            Transaction::Execute(id, execution, fee) => {
+                // Verify the execution.
+                self.verify_execution_with_keyring(execution, execution_keys)?;
+                lap!(timer, "Verify the execution");
+                // Verify the fee, if it exists.
+                if let Some(fee) = fee {
+                    // Compute the execution ID.
+                    let Ok(execution_id) = execution.to_execution_id() else {
+                        bail!("Failed to compute the Merkle root for execution transaction '{id}'")
+                    };
+                    self.verify_fee_with_keyring(fee, execution_id, fee_keys)?;
+                    lap!(timer, "Verify the fee");
+                }
+            }
+            Transaction::Fee(_, fee) => {
+                // Verify the fee.
+                self.verify_fee_with_keyring(fee, fee.deployment_or_execution_id()?, fee_keys)?;
+                lap!(timer, "Verify the fee");
+            }
+        }
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Verifies the transaction in the given view, where the fee proof is verified against `fee_keys`
+    /// and the execution proof is verified against `execution_keys`.
+    ///
+    /// The proofs are borrowed by the view, and are only decoded here, so a caller may inspect the view
+    /// (e.g. its ID or transitions) before paying for the proofs to be decoded.
+    /// Note: This checks the same conditions as `Process::verify_split` on the decoded transaction.
+    #[inline]
+    pub fn verify_view(
+        &self,
+        view: TransactionView<'_, N>,
+        fee_keys: &impl Keyring<N>,
+        execution_keys: &impl Keyring<N>,
+    ) -> Result<()> {
+        // Decode the proofs.
+        let transaction = view.into_transaction()?;
+        // Verify the transaction.
+        self.verify_split(&transaction, fee_keys, execution_keys)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Verifies the program owner and the fee of the given transaction, as a cheap check before full verification.
+    ///
+    /// This checks that:
+    ///  - for a deploy transaction, the program owner signed the deployment ID, and the fee is valid.
+    ///  - for an execute transaction, the fee is valid, if one exists.
+    ///  - for a fee transaction, the fee is valid for the deployment or execution ID it claims to pay for.
+    /// The fee is checked to be well-formed, and its proof is verified against `fee_keys`.
+    ///
+    /// This does *not* check the deployment (i.e. its edition, program, verifying keys, or certificates),
+    /// the execution (i.e. its transitions or proof), that the fee covers the cost of the transaction,
+    /// or that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_owner_and_fee(&self, transaction: &Transaction<N>, fee_keys: &impl Keyring<N>) -> Result<()> {
+        let timer = timer!("Process::verify_owner_and_fee");
+
+        match transaction {
+            Transaction::Deploy(id, owner, deployment, fee) => {
+                // Compute the deployment ID.
+                let Ok(deployment_id) = deployment.to_deployment_id() else {
+                    bail!("Failed to compute the Merkle root for deployment transaction '{id}'")
+                };
+                // Verify the signature corresponds to the deployment ID.
+                ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
+                lap!(timer, "Verify the owner");
+                // Verify the fee.
+                self.verify_fee_with_keyring(fee, deployment_id, fee_keys)?;
+                lap!(timer, "Verify the fee");
+            }
+            Transaction::Execute(id, execution, fee) => {
+                // Verify the fee, if it exists.
+                if let Some(fee) = fee {
+                    // Compute the execution ID.
+                    let Ok(execution_id) = execution.to_execution_id() else {
+                        bail!("Failed to compute the Merkle root for execution transaction '{id}'")
+                    };
+                    self.verify_fee_with_keyring(fee, execution_id, fee_keys)?;
+                    lap!(timer, "Verify the fee");
+                }
+            }
+            Transaction::Fee(_, fee) => {
+                // Verify the fee.
+                self.verify_fee_with_keyring(fee, fee.deployment_or_execution_id()?, fee_keys)?;
+                lap!(timer, "Verify the fee");
+            }
+        }
+
+        finish!(timer);
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Verifies the proofs in the given transaction against the given keyring, returning a
+    /// `VerificationTimeout` error if verification does not complete within the given timeout.
+    ///
+    /// The transaction is checked to be well-formed on the calling thread, and the proofs are verified on a worker
+    /// thread. At most `MAX_VERIFICATION_WORKERS` workers run at once, and the time spent waiting for a worker counts
+    /// towards the timeout. Cancellation is per proof: the execution proof and the fee proof are each verified as a
+    /// single batch, so on timeout, the worker completes the proof in progress (while holding its slot), and skips
+    /// any remaining proofs. As such, the verification work in the background is bounded by the number of workers.
+    ///
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn verify_with_timeout<K: Keyring<N> + Send + 'static>(
+        &self,
+        transaction: &Transaction<N>,
+        keyring: K,
+        timeout: Duration,
+    ) -> Result<()> {
+        // Compute the deadline.
+        let deadline = Instant::now().checked_add(timeout);
+        // Construct the proof bundle, which checks the transaction is well-formed.
+        let bundle = self.to_proof_bundle(transaction)?;
+
+        // Acquire a worker slot, before the deadline.
+        let Some(slot) = VERIFICATION_WORKERS.acquire(deadline) else {
+            return Err(VerificationTimeout::<N> { id: transaction.id(), timeout }.into());
+        };
+
+        // Initialize the cancellation flag.
+        let is_cancelled = Arc::new(AtomicBool::new(false));
+        // Verify the proof bundle on a worker thread.
+        let (sender, receiver) = mpsc::channel();
+        let worker_is_cancelled = is_cancelled.clone();
+        std::thread::spawn(move || {
+            let result = verify_proof_bundle_until(&bundle, &keyring, &worker_is_cancelled);
+            // Release the worker slot.
+            drop(slot);
+            // Note: The receiver is dropped if the verification has timed out.
+            let _ = sender.send(result);
+        });
+
+        // Wait for the verification to complete.
+        let remaining = deadline.map_or(Duration::MAX, |deadline| deadline.saturating_duration_since(Instant::now()));
+        match receiver.recv_timeout(remaining) {
+            Ok(result) => result,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                // Cancel any remaining proofs.
+                is_cancelled.store(true, Ordering::SeqCst);
+                Err(VerificationTimeout::<N> { id: transaction.id(), timeout }.into())
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                bail!("The verification worker for transaction '{}' exited unexpectedly", transaction.id())
+            }
+        }
+    }
+}
+
+/// A counting semaphore, which bounds the number of verification workers.
+struct WorkerSlots {
+    /// The number of available slots.
+    available: Mutex<usize>,
+    /// The condition variable, which is notified when a slot is released.
+    released: Condvar,
+}
+
+impl WorkerSlots {
+    /// Initializes the given number of worker slots.
+    const fn new(num_slots: usize) -> Self {
+        Self { available: Mutex::new(num_slots), released: Condvar::new() }
+    }
+
+    /// Acquires a worker slot, waiting until the given deadline (if any).
+    /// Returns `None` if no slot became available before the deadline.
+    fn acquire(&'static self, deadline: Option<Instant>) -> Option<WorkerSlot> {
+        let mut available = self.available.lock();
+        while *available == 0 {
+            match deadline {
+                Some(deadline) => {
+                    if self.released.wait_until(&mut available, deadline).timed_out() && *available == 0 {
+                        return None;
+                    }
+                }
+                None => self.released.wait(&mut available),
+            }
+        }
+        *available -= 1;
+        Some(WorkerSlot { slots: self })
+    }
+}
+
+/// A worker slot, which is released when dropped.
+struct WorkerSlot {
+    /// The worker slots.
+    slots: &'static WorkerSlots,
+}
+
+impl Drop for WorkerSlot {
+    /// Releases the worker slot.
+    fn drop(&mut self) {
+        *self.slots.available.lock() += 1;
+        self.slots.released.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::ProgramOwner};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_verify_split() {
+        // Sample an execution, and construct a feeless execution transaction.
+        let execution = test_helpers::sample_execution();
+        let transaction = Transaction::from_execution(execution.clone(), None).unwrap();
+
+        // Construct a process with the executed program, and the verifying key for the executed function.
+        let process = test_helpers::sample_process_with_key();
+        let transition = execution.peek().unwrap();
+
+        // Construct a keyring with only the execution verifying key.
+        let verifying_key = process.get_verifying_key(transition.program_id(), transition.function_name()).unwrap();
+        let execution_keys =
+            IndexMap::from([(Locator::new(*transition.program_id(), *transition.function_name()), verifying_key)]);
+        let fee_keys = IndexMap::<Locator<CurrentNetwork>, VerifyingKey<CurrentNetwork>>::new();
+
+        // Ensure the transaction verifies against the separate keyrings.
+        process.verify_split(&transaction, &fee_keys, &execution_keys).unwrap();
+        // Ensure the transaction fails to verify when the execution verifying key is missing.
+        assert!(process.verify_split(&transaction, &execution_keys, &fee_keys).is_err());
+
+        // Construct an execution transaction with a public fee.
+        let rng = &mut TestRng::default();
+        let fee = ledger_test_helpers::sample_fee_public(execution.to_execution_id().unwrap(), rng);
+        let transaction = Transaction::from_execution(execution.clone(), Some(fee)).unwrap();
+
+        // Construct a keyring with only the fee verifying key.
+        let fee_locator =
+            Locator::new(ProgramID::from_str("credits.aleo").unwrap(), Identifier::from_str("fee_public").unwrap());
+        let fee_key = process.get_verifying_key(fee_locator.program_id(), fee_locator.resource()).unwrap();
+        let fee_keys = IndexMap::from([(fee_locator, fee_key.clone())]);
+
+        // Ensure the fee verifies against the credits key, and the execution against its own key.
+        process.verify_split(&transaction, &fee_keys, &execution_keys).unwrap();
+        // Ensure the transaction fails to verify when only the execution keyring has the fee verifying key.
+        let mut all_keys = execution_keys.clone();
+        all_keys.insert(fee_locator, fee_key);
+        let no_keys = IndexMap::<Locator<CurrentNetwork>, VerifyingKey<CurrentNetwork>>::new();
+        assert!(process.verify_split(&transaction, &no_keys, &all_keys).is_err());
+        // Ensure the transaction fails to verify when the keyrings are swapped.
+        assert!(process.verify_split(&transaction, &execution_keys, &fee_keys).is_err());
+    }
+
+    #[test]
+    fn test_verify_view() {
+        // Sample an execution, and encode a feeless execution transaction.
+        let transaction = Transaction::from_execution(test_helpers::sample_execution(), None).unwrap();
+        let bytes = transaction.to_bytes_le().unwrap();
+
+        // Construct a process with the executed program, and the verifying key for the executed function.
+        let process = test_helpers::sample_process_with_key();
+
+        // Ensure the view verifies.
+        let view = Transaction::<CurrentNetwork>::view_from_slice(&bytes).unwrap();
+        process.verify_view(view, &process, &process).unwrap();
+
+        // Replace the execution proof with a well-formed, zeroed proof, which is not covered by the transaction ID.
+        let execution = transaction.execution().unwrap();
+        let proof = execution.proof().unwrap().redacted().unwrap();
+        let execution = Execution::from(execution.transitions().cloned(), execution.global_state_root(), Some(proof));
+        let corrupted = Transaction::from_execution(execution.unwrap(), None).unwrap();
+        assert_eq!(transaction.id(), corrupted.id());
+        // Ensure the view is still read, but fails to verify.
+        let bytes = corrupted.to_bytes_le().unwrap();
+        let view = Transaction::<CurrentNetwork>::view_from_slice(&bytes).unwrap();
+        assert!(process.verify_view(view, &process, &process).is_err());
+    }
+
+    #[test]
+    fn test_verify_owner_and_fee() {
+        let rng = &mut TestRng::default();
+
+        // Fetch transactions.
+        let transactions = [
+            ledger_test_helpers::sample_deployment_transaction(true, rng),
+            ledger_test_helpers::sample_deployment_transaction(false, rng),
+            ledger_test_helpers::sample_execution_transaction_with_fee(true, rng),
+            ledger_test_helpers::sample_fee_public_transaction(rng),
+        ];
+
+        // Construct a new process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Ensure the owner and fee verify.
+        for transaction in &transactions {
+            process.verify_owner_and_fee(transaction, &process).unwrap();
+        }
+
+        // Ensure a deployment with an owner that signed a different ID is rejected.
+        let Transaction::Deploy(id, _, deployment, fee) = &transactions[0] else { unreachable!() };
+        let owner = ProgramOwner::new(&PrivateKey::new(rng).unwrap(), Field::rand(rng), rng).unwrap();
+        let transaction = Transaction::Deploy(*id, owner, deployment.clone(), fee.clone());
+        assert!(process.verify_owner_and_fee(&transaction, &process).is_err());
+
+        // Ensure the fee is rejected when its verifying key is missing.
+        let fee_keys = IndexMap::<Locator<CurrentNetwork>, VerifyingKey<CurrentNetwork>>::new();
+        assert!(process.verify_owner_and_fee(&transactions[0], &fee_keys).is_err());
+    }
+
+    #[test]
+    fn test_verify_with_timeout() {
+        // Sample an execution, and construct a feeless execution transaction.
+        let transaction = Transaction::from_execution(test_helpers::sample_execution(), None).unwrap();
+
+        // Construct a process with the executed program, and the verifying key for the executed function.
+        let process = Arc::new(test_helpers::sample_process_with_key());
+
+        // Ensure the transaction verifies within a generous timeout.
+        process.verify_with_timeout(&transaction, process.clone(), Duration::from_secs(600)).unwrap();
+
+        // Ensure the transaction times out when no time is given.
+        let error = process.verify_with_timeout(&transaction, process.clone(), Duration::ZERO).unwrap_err();
+        let timeout = error.downcast_ref::<VerificationTimeout<CurrentNetwork>>().unwrap();
+        assert_eq!(timeout.id, transaction.id());
+        assert_eq!(timeout.timeout, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_verify_with_timeout_concurrently() {
+        // Sample an execution, and construct a feeless execution transaction.
+        let transaction = Transaction::from_execution(test_helpers::sample_execution(), None).unwrap();
+
+        // Construct a process with the executed program, and the verifying key for the executed function.
+        let process = Arc::new(test_helpers::sample_process_with_key());
+
+        // Start verifications that time out, which leave their workers running in the background.
+        for _ in 0..MAX_VERIFICATION_WORKERS {
+            let _ = process.verify_with_timeout(&transaction, process.clone(), Duration::from_millis(1));
+        }
+        // Ensure more verifications than there are workers all succeed, with a non-zero timeout.
+        let handles = (0..MAX_VERIFICATION_WORKERS + 1)
+            .map(|_| {
+                let (process, transaction) = (process.clone(), transaction.clone());
+                std::thread::spawn(move || {
+                    process.verify_with_timeout(&transaction, process.clone(), Duration::from_secs(600))
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_worker_slots() {
+        let slots: &'static WorkerSlots = Box::leak(Box::new(WorkerSlots::new(2)));
+        let deadline = || Some(Instant::now() + Duration::from_millis(10));
+
+        // Ensure no more than the given number of slots are acquired.
+        let first = slots.acquire(deadline()).unwrap();
+        let second = slots.acquire(deadline()).unwrap();
+        assert!(slots.acquire(deadline()).is_none());
+        // Ensure a released slot is acquired again.
+        drop(first);
+        let third = slots.acquire(deadline()).unwrap();
+        assert!(slots.acquire(deadline()).is_none());
+
+        // Ensure a waiting acquirer is woken when a slot is released.
+        let waiter = std::thread::spawn(move || slots.acquire(None).is_some());
+        std::thread::sleep(Duration::from_millis(10));
+        drop(second);
+        assert!(waiter.join().unwrap());
+        drop(third);
+    }
+}
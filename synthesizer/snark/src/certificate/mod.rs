@@ -84,6 +84,14 @@ impl<N: Network> Certificate<N> {
             }
         }
     }
+
+    /// Returns a copy of the certificate, with every group element set to the identity and every field element set
+    /// to zero. The redacted certificate preserves the structure and serialized size of the certificate,
+    /// but does not verify.
+    pub fn redacted(&self) -> Result<Self> {
+        let pc_proof = crate::proof::redacted_pc_proof::<N>(&self.certificate.pc_proof)?;
+        Ok(Self::new(varuna::Certificate::new(pc_proof)))
+    }
 }
 
 impl<N: Network> Deref for Certificate<N> {
@@ -93,3 +101,26 @@ impl<N: Network> Deref for Certificate<N> {
         &self.certificate
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacted() -> Result<()> {
+        // Sample the certificate.
+        let expected = crate::test_helpers::sample_certificate();
+
+        // Ensure the redacted certificate differs, and is redacted only once.
+        let redacted = expected.redacted()?;
+        assert_ne!(expected, redacted);
+        assert_eq!(redacted, redacted.redacted()?);
+
+        // Ensure the redacted certificate has the same size, and round-trips through the byte codec.
+        let bytes = redacted.to_bytes_le()?;
+        assert_eq!(expected.to_bytes_le()?.len(), bytes.len());
+        assert_eq!(redacted, Certificate::read_le(&bytes[..])?);
+
+        Ok(())
+    }
+}
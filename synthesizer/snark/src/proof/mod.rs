@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use snarkvm_algorithms::polycommit::{kzg10::KZGProof, sonic_pc};
 
 mod bytes;
 mod parse;
@@ -29,6 +30,84 @@ impl<N: Network> Proof<N> {
     pub const fn new(proof: varuna::Proof<N::PairingCurve>) -> Self {
         Self { proof }
     }
+
+    /// Returns a copy of the proof, with every group element set to the identity and every field element set to zero.
+    /// The redacted proof preserves the structure and serialized size of the proof, but does not verify.
+    pub fn redacted(&self) -> Result<Self> {
+        let mut proof = self.proof.clone();
+        // Redact the commitments.
+        let commitments = &mut proof.commitments;
+        for commitment in commitments
+            .witness_commitments
+            .iter_mut()
+            .map(|commitment| &mut commitment.w)
+            .chain(commitments.mask_poly.iter_mut())
+            .chain([&mut commitments.h_0, &mut commitments.g_1, &mut commitments.h_1, &mut commitments.h_2])
+            .chain(commitments.g_a_commitments.iter_mut())
+            .chain(commitments.g_b_commitments.iter_mut())
+            .chain(commitments.g_c_commitments.iter_mut())
+        {
+            commitment.0 = identity();
+        }
+        // Redact the evaluations.
+        let evaluations = &mut proof.evaluations;
+        for evaluation in std::iter::once(&mut evaluations.g_1_eval)
+            .chain(evaluations.g_a_evals.iter_mut())
+            .chain(evaluations.g_b_evals.iter_mut())
+            .chain(evaluations.g_c_evals.iter_mut())
+        {
+            *evaluation = N::Field::zero();
+        }
+        // Redact the sums in the third and fourth messages.
+        for sums in proof.third_msg.sums.iter_mut().flatten().chain(proof.fourth_msg.sums.iter_mut()) {
+            (sums.sum_a, sums.sum_b, sums.sum_c) = (N::Field::zero(), N::Field::zero(), N::Field::zero());
+        }
+        // Redact the evaluation proofs.
+        proof.pc_proof = redacted_pc_proof::<N>(&proof.pc_proof)?;
+        Ok(Self::new(proof))
+    }
+
+    /// Returns the length of the encoded proof at the start of the given bytes, without decoding the proof.
+    /// Note: This does not ensure the proof is well-formed, which is checked when the proof is read.
+    pub fn encoded_len(bytes: &[u8]) -> Result<usize> {
+        // Ensure the version is valid.
+        match bytes.first() {
+            Some(1) => (),
+            Some(version) => bail!("Invalid proof version '{version}'"),
+            None => bail!("Missing the proof version"),
+        }
+        // Return the length of the version, and the proof.
+        Ok(1 + varuna::Proof::<N::PairingCurve>::compressed_len(&bytes[1..])?)
+    }
+}
+
+/// Returns a copy of the given evaluation proofs, with every group element set to the identity and every field
+/// element set to zero.
+/// Note: The individual proofs are not exposed by the batch, so they are redacted through its encoding,
+/// which is the number of proofs, followed by each proof.
+pub(crate) fn redacted_pc_proof<N: Network>(
+    pc_proof: &sonic_pc::BatchLCProof<N::PairingCurve>,
+) -> Result<sonic_pc::BatchLCProof<N::PairingCurve>> {
+    let bytes = pc_proof.to_bytes_le()?;
+    let mut reader = &bytes[..];
+    // Read the number of proofs.
+    let num_proofs = u64::read_le(&mut reader)?;
+    // Write the number of proofs, followed by each redacted proof.
+    let mut redacted = Vec::with_capacity(bytes.len());
+    num_proofs.write_le(&mut redacted)?;
+    for _ in 0..num_proofs {
+        let proof = KZGProof::<N::PairingCurve>::read_le(&mut reader)?;
+        KZGProof::<N::PairingCurve> { w: identity(), random_v: proof.random_v.map(|_| N::Field::zero()) }
+            .write_le(&mut redacted)?;
+    }
+    // Ensure the proofs were fully read.
+    ensure!(reader.is_empty(), "Found {} trailing bytes after the evaluation proofs", reader.len());
+    Ok(FromBytes::read_le(&redacted[..])?)
+}
+
+/// Returns the identity of the group.
+fn identity<G: AffineCurve>() -> G {
+    G::zero()
 }
 
 impl<N: Network> Deref for Proof<N> {
@@ -38,3 +117,31 @@ impl<N: Network> Deref for Proof<N> {
         &self.proof
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacted() -> Result<()> {
+        // Sample the proof.
+        let expected = crate::test_helpers::sample_proof();
+
+        // Ensure the redacted proof differs, but has the same structure.
+        let redacted = expected.redacted()?;
+        assert_ne!(expected, redacted);
+        assert!(redacted.check_batch_sizes().is_ok());
+        assert_eq!(expected.is_hiding(), redacted.is_hiding());
+        // Ensure every element is redacted.
+        assert!(redacted.commitments.h_0.0.is_zero());
+        assert!(redacted.evaluations.g_1_eval.is_zero());
+        assert_eq!(redacted, redacted.redacted()?);
+
+        // Ensure the redacted proof has the same size, and round-trips through the byte codec.
+        let bytes = redacted.to_bytes_le()?;
+        assert_eq!(expected.to_bytes_le()?.len(), bytes.len());
+        assert_eq!(redacted, Proof::read_le(&bytes[..])?);
+
+        Ok(())
+    }
+}